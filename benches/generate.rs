@@ -0,0 +1,44 @@
+//! Compares generating passwords from a word list which is filtered on every call with one which
+//! is indexed by word length.
+//!
+//! Run with `cargo bench`.
+
+#[macro_use]
+extern crate criterion;
+extern crate rand;
+extern crate xkcd_pass;
+
+use criterion::Criterion;
+use rand::{SeedableRng, StdRng};
+use xkcd_pass::{generate_password_with_rng, Configuration, SimpleEnglish};
+use xkcd_pass::word_list::{FileWordList, FileWordListOptions, IndexedWordList};
+
+const SIMPLE_EN: &str = include_str!("../src/word_list/dictionaries/simple_en.txt");
+
+fn bench_generate(c: &mut Criterion) {
+    let config = Configuration::default();
+    let seed: &[_] = &[1, 2, 3, 4];
+
+    // A FileWordList has no index, so the words are filtered by length for every password
+    let filtered = FileWordList::from_reader(SIMPLE_EN.as_bytes(), &FileWordListOptions::default()).unwrap();
+    let mut rgen = StdRng::from_seed(seed);
+    c.bench_function("generate_password filtered", |b| b.iter(|| generate_password_with_rng(&config, &filtered, &mut rgen)));
+
+    let indexed = IndexedWordList::from_word_list(&filtered);
+    let mut rgen = StdRng::from_seed(seed);
+    c.bench_function("generate_password indexed", |b| b.iter(|| generate_password_with_rng(&config, &indexed, &mut rgen)));
+
+    let simple_english = SimpleEnglish::new();
+    let mut rgen = StdRng::from_seed(seed);
+    c.bench_function("generate_password SimpleEnglish", |b| b.iter(|| generate_password_with_rng(&config, &simple_english, &mut rgen)));
+}
+
+fn bench_word_list(c: &mut Criterion) {
+    c.bench_function("SimpleEnglish::new", |b| b.iter(SimpleEnglish::new));
+    c.bench_function("FileWordList::from_reader", |b| {
+        b.iter(|| FileWordList::from_reader(SIMPLE_EN.as_bytes(), &FileWordListOptions::default()).unwrap())
+    });
+}
+
+criterion_group!(benches, bench_generate, bench_word_list);
+criterion_main!(benches);
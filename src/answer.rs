@@ -0,0 +1,188 @@
+//! Generates random answers to security questions, such as "What is your mother's maiden name?".
+//!
+//! The answers are short phrases of words separated by spaces, so they can be read out over the
+//! phone, but are as random as a password. An answer starts with a capital letter and can end in
+//! a punctuation mark, such as `Mother bright good price.`
+//!
+//! # Example
+//!
+//! ```
+//! use xkcd_pass::SimpleEnglish;
+//! use xkcd_pass::answer::{generate_answer, AnswerConfiguration};
+//!
+//! let config = AnswerConfiguration::security_question();
+//! let answer = generate_answer(&config, &SimpleEnglish::new());
+//! assert_eq!(answer.split(' ').count(), 4);
+//! ```
+
+use rand::{Rng, thread_rng};
+
+use super::configuration::ConfigError;
+use super::generator::{choose_list_words, wipe, GenerateError};
+use super::word_list::WordList;
+
+#[cfg(test)]
+mod tests;
+
+/// The configuration for security question answers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnswerConfiguration {
+    /// The number of words in the answer
+    pub num_words: u8,
+    /// The minimum length of the words in the answer
+    pub min_length: u8,
+    /// The maximum length of the words in the answer
+    pub max_length: u8,
+    /// Capitalise the first letter of the answer, like a sentence
+    pub sentence_case: bool,
+    /// The punctuation marks the answer can end in. One is chosen at random, and the answer has
+    /// no punctuation if this is empty
+    pub punctuation: Vec<char>,
+}
+
+impl AnswerConfiguration {
+    /// Creates the security question preset: 4 words of 3 to 6 letters in sentence case, ending
+    /// in `.`, `!` or `?`.
+    pub fn security_question() -> AnswerConfiguration {
+        return AnswerConfiguration {
+            num_words: 4,
+            min_length: 3,
+            max_length: 6,
+            sentence_case: true,
+            punctuation: vec!['.', '!', '?'],
+        };
+    }
+
+    /// Checks that an answer can be generated with this configuration and the given word list.
+    pub fn validate<A: WordList>(&self, word_list: &A) -> Result<(), ConfigError> {
+        let min_length = self.min_length;
+        let max_length = self.max_length;
+        if min_length > max_length {
+            return Err(ConfigError::InvalidWordLength { min_length, max_length });
+        }
+        if self.num_words > 0 && num_candidates(word_list, min_length, max_length) == 0 {
+            return Err(ConfigError::NoMatchingWords { min_length, max_length });
+        }
+        return Ok(());
+    }
+
+    /// The entropy of the answers in bits, assuming the attacker knows the configuration and the
+    /// word list.
+    ///
+    /// ```
+    /// use xkcd_pass::SimpleEnglish;
+    /// use xkcd_pass::answer::AnswerConfiguration;
+    ///
+    /// let entropy = AnswerConfiguration::security_question().entropy(&SimpleEnglish::new());
+    /// assert!(entropy > 30.0);
+    /// ```
+    pub fn entropy<A: WordList>(&self, word_list: &A) -> f64 {
+        let num_candidates = num_candidates(word_list, self.min_length, self.max_length);
+        let mut entropy = self.num_words as f64 * (num_candidates.max(1) as f64).log2();
+        if !self.punctuation.is_empty() {
+            entropy += (self.punctuation.len() as f64).log2();
+        }
+        return entropy;
+    }
+}
+
+impl Default for AnswerConfiguration {
+    /// The security question preset
+    fn default() -> AnswerConfiguration {
+        return AnswerConfiguration::security_question();
+    }
+}
+
+/// Generates an answer to a security question with the given configuration and word list.
+///
+/// # Panics
+///
+/// Panics if the configuration is invalid for the word list. Use `try_generate_answer` to handle
+/// an invalid configuration instead.
+pub fn generate_answer<A: WordList>(config: &AnswerConfiguration, word_list: &A) -> String {
+    return generate_answer_with_rng(config, word_list, &mut thread_rng());
+}
+
+/// Generates an answer to a security question with the given configuration, word list and random
+/// number generator.
+///
+/// # Panics
+///
+/// Panics if the configuration is invalid for the word list.
+pub fn generate_answer_with_rng<A: WordList, R: Rng>(config: &AnswerConfiguration, word_list: &A, rgen: &mut R) -> String {
+    return match try_generate_answer_with_rng(config, word_list, rgen) {
+        Ok(answer) => answer,
+        Err(err) => panic!("{}", err),
+    };
+}
+
+/// Generates an answer to a security question, returning an error if the configuration is
+/// invalid.
+pub fn try_generate_answer<A: WordList>(config: &AnswerConfiguration, word_list: &A) -> Result<String, GenerateError> {
+    return try_generate_answer_with_rng(config, word_list, &mut thread_rng());
+}
+
+/// Generates an answer to a security question with the given random number generator, returning
+/// an error if the configuration is invalid.
+pub fn try_generate_answer_with_rng<A: WordList, R: Rng>(config: &AnswerConfiguration, word_list: &A, rgen: &mut R) -> Result<String, GenerateError> {
+    config.validate(word_list)?;
+
+    let mut chosen_words = choose_list_words(word_list, config.min_length, config.max_length, config.num_words as usize, rgen);
+    for word in chosen_words.iter_mut() {
+        let lower_case = word.to_lowercase();
+        wipe(word);
+        *word = lower_case;
+    }
+    let punctuation = match config.punctuation.len() {
+        0 => None,
+        num_punctuation => Some(config.punctuation[rgen.gen_range(0, num_punctuation)]),
+    };
+
+    // Allocate enough space for the whole answer up front, so the buffer is never moved. A
+    // capital letter can be longer or shorter in bytes than its lowercase letter, so this is only
+    // an upper bound
+    let first_char = chosen_words.first().and_then(|a| a.chars().next());
+    let capital_growth = match first_char {
+        Some(first_char) if config.sentence_case => first_char.to_uppercase().map(|a| a.len_utf8()).sum::<usize>().saturating_sub(first_char.len_utf8()),
+        _ => 0,
+    };
+    let capacity = chosen_words.iter().map(|a| a.len()).sum::<usize>()
+        + chosen_words.len().saturating_sub(1)
+        + capital_growth
+        + punctuation.map_or(0, |a| a.len_utf8());
+    let mut answer = String::with_capacity(capacity);
+    let initial_capacity = answer.capacity();
+
+    for (i, word) in chosen_words.iter().enumerate() {
+        if i == 0 && config.sentence_case {
+            let mut word_iter = word.chars();
+            if let Some(first_char) = word_iter.next() {
+                answer.extend(first_char.to_uppercase());
+            }
+            answer.push_str(word_iter.as_str());
+        } else {
+            if i != 0 {
+                answer.push(' ');
+            }
+            answer.push_str(word);
+        }
+    }
+    if let Some(punctuation) = punctuation {
+        answer.push(punctuation);
+    }
+    debug_assert_eq!(answer.capacity(), initial_capacity, "the answer buffer was moved");
+
+    for word in chosen_words.iter_mut() {
+        wipe(word);
+    }
+
+    return Ok(answer);
+}
+
+/// The number of words in the word list between the minimum and maximum length
+fn num_candidates<A: WordList>(word_list: &A, min_length: u8, max_length: u8) -> usize {
+    return match word_list.get_words_slice(min_length, max_length) {
+        Some(words) => words.len(),
+        None => word_list.get_words(min_length, max_length).len(),
+    };
+}
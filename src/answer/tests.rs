@@ -0,0 +1,55 @@
+use rand::{SeedableRng, StdRng};
+use super::{try_generate_answer_with_rng, AnswerConfiguration};
+use super::super::configuration::ConfigError;
+use super::super::generator::GenerateError;
+use super::super::word_list::{FileWordList, FileWordListOptions, SimpleEnglish};
+
+#[test]
+pub fn test_generate_answer() {
+    let word_list = SimpleEnglish::new();
+    let seed: &[_] = &[1, 2, 3];
+    let config = AnswerConfiguration::security_question();
+
+    let answer = try_generate_answer_with_rng(&config, &word_list, &mut StdRng::from_seed(seed)).unwrap();
+    let again = try_generate_answer_with_rng(&config, &word_list, &mut StdRng::from_seed(seed)).unwrap();
+    assert_eq!(answer, again);
+
+    let (phrase, punctuation) = answer.split_at(answer.len() - 1);
+    assert!(config.punctuation.contains(&punctuation.chars().next().unwrap()));
+    let words: Vec<&str> = phrase.split(' ').collect();
+    assert_eq!(words.len(), 4);
+    assert!(words[0].chars().next().unwrap().is_uppercase());
+    for word in words {
+        assert!(word.len() >= 3 && word.len() <= 6);
+    }
+}
+
+#[test]
+pub fn test_answer_options() {
+    let word_list = FileWordList::from_reader("ÉCOLE\nhorse".as_bytes(), &FileWordListOptions::default()).unwrap();
+    let seed: &[_] = &[4];
+    let config = AnswerConfiguration {
+        num_words: 3,
+        min_length: 5,
+        max_length: 5,
+        sentence_case: false,
+        punctuation: Vec::new(),
+    };
+    let answer = try_generate_answer_with_rng(&config, &word_list, &mut StdRng::from_seed(seed)).unwrap();
+    assert_eq!(answer.split(' ').count(), 3);
+    assert!(answer.chars().all(|a| a == ' ' || a.is_lowercase()));
+    assert_eq!(config.entropy(&word_list), 3.0);
+
+    let config = AnswerConfiguration { min_length: 6, max_length: 9, ..config };
+    assert_eq!(try_generate_answer_with_rng(&config, &word_list, &mut StdRng::from_seed(seed)),
+               Err(GenerateError::InvalidConfiguration(ConfigError::NoMatchingWords { min_length: 6, max_length: 9 })));
+}
+
+#[test]
+pub fn test_capital_letter_shorter_in_bytes() {
+    // The long s is two bytes but its capital is one
+    let word_list = FileWordList::from_reader("ſtar".as_bytes(), &FileWordListOptions::default()).unwrap();
+    let seed: &[_] = &[5];
+    let config = AnswerConfiguration { num_words: 1, min_length: 4, max_length: 4, sentence_case: true, punctuation: Vec::new() };
+    assert_eq!(try_generate_answer_with_rng(&config, &word_list, &mut StdRng::from_seed(seed)).unwrap(), "Star");
+}
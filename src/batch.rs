@@ -0,0 +1,160 @@
+//! Generates batches of passwords, optionally with no duplicates within the batch.
+//!
+//! # Example
+//!
+//! ```
+//! use xkcd_pass::{try_generate_passwords, Configuration, SimpleEnglish};
+//! use xkcd_pass::batch::Uniqueness;
+//!
+//! let passwords = try_generate_passwords(&Configuration::default(), &SimpleEnglish::new(), 100, Uniqueness::UniqueWords).unwrap();
+//! assert_eq!(passwords.len(), 100);
+//! ```
+
+use std::collections::HashSet;
+use rand::{Rng, thread_rng};
+
+use super::configuration::Configuration;
+use super::generator::{assemble_password, choose_config_words, wipe, GenerateError};
+use super::word_list::WordList;
+
+#[cfg(test)]
+mod tests;
+
+/// The number of duplicate passwords in a row after which a batch gives up
+const MAX_DUPLICATE_ATTEMPTS: usize = 1000;
+
+/// How the passwords in a batch must differ from each other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Uniqueness {
+    /// The same password may appear more than once
+    AllowDuplicates,
+    /// No two passwords are the same
+    UniquePasswords,
+    /// No two passwords are made from the same words, ignoring the order and case of the words.
+    /// This also means no two passwords are the same
+    UniqueWords,
+}
+
+/// Generates a batch of passwords with the given configuration and word list. The same password
+/// may appear more than once, use `try_generate_passwords` to prevent duplicates.
+///
+/// # Panics
+///
+/// Panics if the configuration is invalid for the word list.
+///
+/// ```
+/// use xkcd_pass::{generate_passwords, Configuration, SimpleEnglish};
+///
+/// let passwords = generate_passwords(&Configuration::default(), &SimpleEnglish::new(), 10);
+/// assert_eq!(passwords.len(), 10);
+/// ```
+pub fn generate_passwords<A: WordList>(config: &Configuration, word_list: &A, num_passwords: usize) -> Vec<String> {
+    return match try_generate_passwords(config, word_list, num_passwords, Uniqueness::AllowDuplicates) {
+        Ok(passwords) => passwords,
+        Err(err) => panic!("{}", err),
+    };
+}
+
+/// Generates a batch of passwords with the given configuration and word list, returning an error
+/// if the configuration is invalid or not enough unique passwords can be generated.
+pub fn try_generate_passwords<A: WordList>(config: &Configuration, word_list: &A, num_passwords: usize, uniqueness: Uniqueness) -> Result<Vec<String>, GenerateError> {
+    return try_generate_passwords_with_rng(config, word_list, num_passwords, uniqueness, &mut thread_rng());
+}
+
+/// Generates a batch of passwords with the given configuration, word list and random number
+/// generator, returning an error if the configuration is invalid or not enough unique passwords
+/// can be generated.
+///
+/// When the passwords must be unique, duplicates are thrown away and generated again. If the
+/// configuration can make fewer word sets than the number of passwords requested an error is
+/// returned straight away, otherwise an error is returned after too many duplicates in a row.
+pub fn try_generate_passwords_with_rng<A: WordList, R: Rng>(config: &Configuration, word_list: &A, num_passwords: usize, uniqueness: Uniqueness, rgen: &mut R) -> Result<Vec<String>, GenerateError> {
+    config.validate(word_list)?;
+
+    if uniqueness == Uniqueness::UniqueWords {
+        let possible = num_word_sets(config, word_list);
+        if possible < num_passwords as u128 {
+            return Err(GenerateError::NotEnoughWordSets { requested: num_passwords, possible });
+        }
+    }
+
+    let mut passwords = Vec::with_capacity(num_passwords);
+    let mut seen_passwords = HashSet::new();
+    let mut seen_word_sets = HashSet::new();
+    let mut duplicate_attempts = 0;
+    while passwords.len() < num_passwords {
+        let chosen_words = choose_config_words(config, word_list, rgen);
+        let word_set = match uniqueness {
+            Uniqueness::UniqueWords => Some(word_set(&chosen_words)),
+            _ => None,
+        };
+        let mut password = assemble_password(config, chosen_words, rgen);
+
+        let is_new = match (uniqueness, word_set) {
+            (Uniqueness::UniqueWords, Some(word_set)) => {
+                if seen_word_sets.contains(&word_set) {
+                    wipe_strings(word_set);
+                    false
+                } else {
+                    seen_word_sets.insert(word_set)
+                }
+            },
+            (Uniqueness::UniquePasswords, _) => !seen_passwords.contains(&password) && seen_passwords.insert(password.clone()),
+            _ => true,
+        };
+        if !is_new {
+            wipe(&mut password);
+            duplicate_attempts += 1;
+            if duplicate_attempts >= MAX_DUPLICATE_ATTEMPTS {
+                let generated = passwords.len();
+                wipe_strings(passwords);
+                wipe_strings(seen_passwords.drain());
+                wipe_strings(seen_word_sets.drain().flatten());
+                return Err(GenerateError::NotEnoughUniquePasswords { requested: num_passwords, generated });
+            }
+            continue;
+        }
+
+        duplicate_attempts = 0;
+        passwords.push(password);
+    }
+
+    // The seen passwords and word sets are copies of the passwords
+    wipe_strings(seen_passwords.drain());
+    wipe_strings(seen_word_sets.drain().flatten());
+    return Ok(passwords);
+}
+
+/// Wipes strings which held passwords or their words
+fn wipe_strings<I: IntoIterator<Item = String>>(strings: I) {
+    for mut string in strings {
+        wipe(&mut string);
+    }
+}
+
+/// The words of a password in lower case and sorted, so passwords made from the same words have
+/// the same word set
+fn word_set(chosen_words: &[String]) -> Vec<String> {
+    let mut word_set: Vec<String> = chosen_words.iter().map(|a| a.to_lowercase()).collect();
+    word_set.sort();
+    return word_set;
+}
+
+/// The number of different sets of words the configuration can choose, ignoring the order and
+/// case of the words. Words can be chosen more than once, so this is the number of multisets
+fn num_word_sets<A: WordList>(config: &Configuration, word_list: &A) -> u128 {
+    // Words which only differ by case make the same word set
+    let candidates: HashSet<String> = word_list.get_words(config.words.min_length, config.words.max_length)
+                                               .iter().map(|a| a.to_lowercase()).collect();
+    let num_candidates = candidates.len() as u128;
+
+    // The binomial coefficient (num_candidates + num_words - 1) choose num_words
+    let mut num_sets: u128 = 1;
+    for i in 1..=config.words.num_words as u128 {
+        num_sets = match num_sets.checked_mul(num_candidates + i - 1) {
+            Some(product) => product / i,
+            None => return u128::MAX,
+        };
+    }
+    return num_sets;
+}
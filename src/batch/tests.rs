@@ -0,0 +1,63 @@
+use std::collections::HashSet;
+use rand::{SeedableRng, StdRng};
+use super::{try_generate_passwords_with_rng, Uniqueness};
+use super::super::configuration::Configuration;
+use super::super::generator::GenerateError;
+use super::super::word_list::{FileWordList, FileWordListOptions};
+
+/// A configuration which joins two words with a '-' and makes no other random choices
+fn two_word_config() -> Configuration {
+    let mut config = Configuration::xkcd();
+    config.words.num_words = 2;
+    config.words.transformations.clear();
+    return config;
+}
+
+#[test]
+pub fn test_unique_passwords() {
+    let word_list = FileWordList::from_reader("apple\nmango\nlemon".as_bytes(), &FileWordListOptions::default()).unwrap();
+    let config = two_word_config();
+    let seed: &[_] = &[7];
+
+    // 3 words can make 9 different passwords
+    let passwords = try_generate_passwords_with_rng(&config, &word_list, 9, Uniqueness::UniquePasswords, &mut StdRng::from_seed(seed)).unwrap();
+    let unique: HashSet<&String> = passwords.iter().collect();
+    assert_eq!(unique.len(), 9);
+
+    let result = try_generate_passwords_with_rng(&config, &word_list, 10, Uniqueness::UniquePasswords, &mut StdRng::from_seed(seed));
+    assert_eq!(result, Err(GenerateError::NotEnoughUniquePasswords { requested: 10, generated: 9 }));
+}
+
+#[test]
+pub fn test_unique_words() {
+    let word_list = FileWordList::from_reader("apple\nmango\nlemon".as_bytes(), &FileWordListOptions::default()).unwrap();
+    let config = two_word_config();
+    let seed: &[_] = &[7];
+
+    // Ignoring the order, 3 words can make 6 different pairs
+    let passwords = try_generate_passwords_with_rng(&config, &word_list, 6, Uniqueness::UniqueWords, &mut StdRng::from_seed(seed)).unwrap();
+    let word_sets: HashSet<Vec<&str>> = passwords.iter().map(|a| {
+        let mut words: Vec<&str> = a.split('-').collect();
+        words.sort();
+        words
+    }).collect();
+    assert_eq!(word_sets.len(), 6);
+
+    let result = try_generate_passwords_with_rng(&config, &word_list, 7, Uniqueness::UniqueWords, &mut StdRng::from_seed(seed));
+    assert_eq!(result, Err(GenerateError::NotEnoughWordSets { requested: 7, possible: 6 }));
+
+    // Words which only differ by case are the same word
+    let word_list = FileWordList::from_reader("apple\nApple\nmango".as_bytes(), &FileWordListOptions::default()).unwrap();
+    let result = try_generate_passwords_with_rng(&config, &word_list, 4, Uniqueness::UniqueWords, &mut StdRng::from_seed(seed));
+    assert_eq!(result, Err(GenerateError::NotEnoughWordSets { requested: 4, possible: 3 }));
+
+    // "a b" and "c" are a different set of words to "a" and "b c"
+    let word_list = FileWordList::from_reader("a b
+c
+a
+b c".as_bytes(), &FileWordListOptions::default()).unwrap();
+    let mut config = two_word_config();
+    config.words.min_length = 1;
+    let passwords = try_generate_passwords_with_rng(&config, &word_list, 10, Uniqueness::UniqueWords, &mut StdRng::from_seed(seed)).unwrap();
+    assert_eq!(passwords.len(), 10);
+}
@@ -0,0 +1,407 @@
+//! Command line interface for the XKCD password generator.
+//!
+//! Run `xkcd-pass --help` for the list of options.
+
+#![allow(clippy::needless_return)]
+
+extern crate xkcd_pass;
+
+use std::env;
+use std::process;
+
+use xkcd_pass::{calculate_entropy, try_generate_passwords, Configuration, SimpleEnglish};
+use xkcd_pass::answer::{try_generate_answer, AnswerConfiguration};
+use xkcd_pass::batch::Uniqueness;
+use xkcd_pass::configuration::{Preset, WordTransformations, SeperatorTypes, PaddingTypes, PaddingCharTypes};
+use xkcd_pass::word_list::{FileWordList, WordList};
+use xkcd_pass::dice::dice_configuration;
+#[cfg(any(feature = "eff-large", feature = "eff-short"))]
+use xkcd_pass::generate_password_from_dice;
+#[cfg(any(feature = "eff-large", feature = "eff-short"))]
+use xkcd_pass::word_list::DicewareWordList;
+#[cfg(feature = "eff-large")]
+use xkcd_pass::word_list::EffLarge;
+#[cfg(feature = "eff-short")]
+use xkcd_pass::word_list::{EffShort, EffShortUniquePrefix};
+#[cfg(feature = "german")]
+use xkcd_pass::word_list::German;
+#[cfg(feature = "french")]
+use xkcd_pass::word_list::French;
+#[cfg(feature = "spanish")]
+use xkcd_pass::word_list::Spanish;
+#[cfg(feature = "dutch")]
+use xkcd_pass::word_list::Dutch;
+#[cfg(feature = "italian")]
+use xkcd_pass::word_list::Italian;
+
+const USAGE: &str = "Usage: xkcd-pass [OPTIONS]
+
+Generates XKCD-style passwords.
+
+Options:
+  -p, --preset <NAME>               Start from a preset configuration: default, appleid,
+                                    ntml, xkcd, web16, web32, wifi, securityq or weblong
+                                    [default: default]
+      --list-presets                Print the presets and their descriptions
+  -c, --config <FILE>               Start from a JSON, TOML or YAML configuration file
+                                    instead of a preset
+  -n, --count <N>                   The number of passwords to generate [default: 1]
+  -u, --unique <KIND>               Prevent duplicates within the generated passwords:
+                                    none, passwords or words. With words, no two
+                                    passwords use the same set of words [default: none]
+  -d, --dictionary <NAME>           The built in dictionary to use: simple-english,
+                                    eff-large, eff-short, eff-short-unique-prefix,
+                                    german, french, spanish, dutch or italian. Every
+                                    dictionary except simple-english needs the cargo
+                                    feature of the same name, or eff-short for
+                                    eff-short-unique-prefix [default: simple-english]
+  -w, --word-list <FILE>            Use a newline separated word list instead of the
+                                    built in English dictionary. Text after a '#' is
+                                    ignored
+      --dice <ROLLS>                Choose the words with physical dice rolls instead of
+                                    a random number generator, e.g. '41523 21334'.
+                                    Needs one of the EFF dictionaries. The random
+                                    choices of the preset or configuration file are
+                                    removed, and -w, -n, -u, -e and -a cannot be used
+  -a, --answer                      Generate answers to security questions, such as
+                                    'Mother bright good price.', instead of passwords.
+                                    Only --num-words, --min-length and --max-length of
+                                    the overrides apply, and -p, -c and -u cannot be used
+  -e, --entropy                     Print the entropy of the chosen settings
+  -h, --help                        Print this help message
+  -V, --version                     Print the version
+
+Configuration overrides:
+      --num-words <N>               The number of words in the password
+      --min-length <N>              The minimum length of the words
+      --max-length <N>              The maximum length of the words
+      --transformations <NAMES>     Comma separated transformations applied in order:
+                                    capitalise-first, capitalise-non-first, lower-case,
+                                    upper-case, alternating-lower-upper,
+                                    random-lower-upper, random-character-case,
+                                    leetspeak, random-leetspeak or reverse
+      --seperator-type <NAME>       single-character, random-character or
+                                    random-character-per-gap
+      --seperators <CHARS>          The possible seperator characters
+      --digits-before <N>           The number of padding digits before the password
+      --digits-after <N>            The number of padding digits after the password
+      --padding <TYPE>              fixed:<BEFORE>,<AFTER> or adaptive:<LENGTH>
+      --padding-character-type <NAME>
+                                    single-character, random-character or
+                                    seperator-character
+      --padding-chars <CHARS>       The possible padding characters";
+
+/// The options given on the command line
+struct Options {
+    config: Configuration,
+    count: usize,
+    uniqueness: Uniqueness,
+    word_list: Option<String>,
+    dictionary: String,
+    dice: Option<String>,
+    print_entropy: bool,
+    answer: Option<AnswerConfiguration>,
+}
+
+pub fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("xkcd-pass: {}", err);
+            eprintln!("Try 'xkcd-pass --help' for more information.");
+            process::exit(2);
+        },
+    };
+
+    let result = match options.word_list {
+        _ if options.dice.is_some() => run_dice(&options),
+        Some(ref path) => {
+            FileWordList::from_path(path).map_err(|err| format!("could not read word list '{}': {}", path, err))
+                                         .and_then(|word_list| run(&options, &word_list))
+        },
+        None => run_with_dictionary(&options),
+    };
+    if let Err(err) = result {
+        eprintln!("xkcd-pass: {}", err);
+        process::exit(1);
+    }
+}
+
+/// Generates the passwords with one of the built in dictionaries
+fn run_with_dictionary(options: &Options) -> Result<(), String> {
+    return match options.dictionary.as_str() {
+        "simple-english" => run(options, &SimpleEnglish::new()),
+        #[cfg(feature = "eff-large")]
+        "eff-large" => run(options, &EffLarge::new()),
+        #[cfg(feature = "eff-short")]
+        "eff-short" => run(options, &EffShort::new()),
+        #[cfg(feature = "eff-short")]
+        "eff-short-unique-prefix" => run(options, &EffShortUniquePrefix::new()),
+        #[cfg(feature = "german")]
+        "german" => run(options, &German::new()),
+        #[cfg(feature = "french")]
+        "french" => run(options, &French::new()),
+        #[cfg(feature = "spanish")]
+        "spanish" => run(options, &Spanish::new()),
+        #[cfg(feature = "dutch")]
+        "dutch" => run(options, &Dutch::new()),
+        #[cfg(feature = "italian")]
+        "italian" => run(options, &Italian::new()),
+        name => Err(format!("unknown or disabled dictionary '{}'", name)),
+    };
+}
+
+/// Generates a password from dice rolls with one of the EFF dictionaries
+#[cfg(any(feature = "eff-large", feature = "eff-short"))]
+fn run_dice(options: &Options) -> Result<(), String> {
+    return match options.dictionary.as_str() {
+        #[cfg(feature = "eff-large")]
+        "eff-large" => print_dice_password(options, &EffLarge::new()),
+        #[cfg(feature = "eff-short")]
+        "eff-short" => print_dice_password(options, &EffShort::new()),
+        #[cfg(feature = "eff-short")]
+        "eff-short-unique-prefix" => print_dice_password(options, &EffShortUniquePrefix::new()),
+        name => Err(format!("the '{}' dictionary cannot be used with dice rolls", name)),
+    };
+}
+
+#[cfg(not(any(feature = "eff-large", feature = "eff-short")))]
+fn run_dice(_options: &Options) -> Result<(), String> {
+    return Err("dice rolls need the eff-large or eff-short feature".to_string());
+}
+
+#[cfg(any(feature = "eff-large", feature = "eff-short"))]
+fn print_dice_password<A: DicewareWordList>(options: &Options, word_list: &A) -> Result<(), String> {
+    let rolls = options.dice.as_deref().unwrap_or("");
+    let password = generate_password_from_dice(&options.config, word_list, rolls).map_err(|err| err.to_string())?;
+    println!("{}", password);
+    return Ok(());
+}
+
+/// Prints the entropy and generates the passwords
+fn run<A: WordList>(options: &Options, word_list: &A) -> Result<(), String> {
+    if let Some(ref answer_config) = options.answer {
+        return run_answers(options, answer_config, word_list);
+    }
+
+    // Check the configuration before anything is printed, so an invalid configuration only
+    // prints the error
+    options.config.validate(word_list).map_err(|err| format!("invalid configuration: {}", err))?;
+
+    if options.print_entropy {
+        let entropy = calculate_entropy(&options.config, word_list).map_err(|err| err.to_string())?;
+        println!("Blind entropy: {:.2} - {:.2} bits", entropy.blind_min, entropy.blind_max);
+        println!("Seen entropy: {:.2} bits", entropy.seen);
+    }
+
+    let passwords = try_generate_passwords(&options.config, word_list, options.count, options.uniqueness).map_err(|err| err.to_string())?;
+    for password in passwords {
+        println!("{}", password);
+    }
+    return Ok(());
+}
+
+/// Prints the entropy and generates the security question answers
+fn run_answers<A: WordList>(options: &Options, config: &AnswerConfiguration, word_list: &A) -> Result<(), String> {
+    config.validate(word_list).map_err(|err| format!("invalid configuration: {}", err))?;
+
+    if options.print_entropy {
+        println!("Entropy: {:.2} bits", config.entropy(word_list));
+    }
+
+    for _ in 0..options.count {
+        println!("{}", try_generate_answer(config, word_list).map_err(|err| err.to_string())?);
+    }
+    return Ok(());
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    // Pair up every option with its value
+    let mut pairs = Vec::new();
+    let mut i = 0;
+    while i < args.len() {
+        let arg = args[i].as_str();
+        match arg {
+            "-h" | "--help" | "-V" | "--version" | "-e" | "--entropy" | "-a" | "--answer" | "--list-presets" => {
+                pairs.push((arg, ""));
+                i += 1;
+            },
+            _ if !arg.starts_with('-') => {
+                return Err(format!("unexpected argument '{}'", arg));
+            },
+            _ => {
+                // A value starting with '-' is the next option rather than a value, except for a bare '-'
+                match args.get(i + 1) {
+                    Some(val) if val == "-" || !val.starts_with('-') => pairs.push((arg, val.as_str())),
+                    _ => return Err(format!("missing value for '{}'", arg)),
+                }
+                i += 2;
+            },
+        }
+    }
+
+    // The preset or configuration file has to be loaded before any of the overrides are applied
+    let mut config = Configuration::default();
+    for &(arg, val) in &pairs {
+        if arg == "-p" || arg == "--preset" {
+            config = parse_preset(val)?;
+        } else if arg == "-c" || arg == "--config" {
+            config = Configuration::from_file(val).map_err(|err| format!("could not load configuration '{}': {}", val, err))?;
+        }
+    }
+
+    // Dice rolls make a single password from one of the EFF dictionaries, so the options for
+    // anything else do not apply. The overrides can still be used, but must not be random
+    if pairs.iter().any(|&(arg, _)| arg == "--dice") {
+        let unsupported = ["-w", "--word-list", "-n", "--count", "-u", "--unique", "-e", "--entropy", "-a", "--answer"];
+        if let Some(&(arg, _)) = pairs.iter().find(|&&(arg, _)| unsupported.contains(&arg)) {
+            return Err(format!("option '{}' cannot be used with '--dice'", arg));
+        }
+        config = dice_configuration(&config);
+    }
+
+    // Answers are only made of words, so the options for the rest of a password do not apply
+    let mut answer = None;
+    if pairs.iter().any(|&(arg, _)| arg == "-a" || arg == "--answer") {
+        let unsupported = ["-p", "--preset", "-c", "--config", "-u", "--unique", "--transformations", "--seperator-type", "--seperators",
+                           "--digits-before", "--digits-after", "--padding", "--padding-character-type", "--padding-chars"];
+        if let Some(&(arg, _)) = pairs.iter().find(|&&(arg, _)| unsupported.contains(&arg)) {
+            return Err(format!("option '{}' cannot be used with '--answer'", arg));
+        }
+        let mut answer_config = AnswerConfiguration::security_question();
+        for &(arg, val) in &pairs {
+            match arg {
+                "--num-words" => answer_config.num_words = parse_number(arg, val)?,
+                "--min-length" => answer_config.min_length = parse_number(arg, val)?,
+                "--max-length" => answer_config.max_length = parse_number(arg, val)?,
+                _ => {},
+            }
+        }
+        answer = Some(answer_config);
+    }
+
+    let mut options = Options {
+        config,
+        count: 1,
+        uniqueness: Uniqueness::AllowDuplicates,
+        word_list: None,
+        dictionary: "simple-english".to_string(),
+        dice: None,
+        print_entropy: false,
+        answer,
+    };
+
+    for (arg, val) in pairs {
+        match arg {
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                process::exit(0);
+            },
+            "-V" | "--version" => {
+                println!("xkcd-pass {}", env!("CARGO_PKG_VERSION"));
+                process::exit(0);
+            },
+            "--list-presets" => {
+                let width = Preset::ALL.iter().map(|a| a.name().len()).max().unwrap_or(0);
+                for preset in Preset::ALL {
+                    println!("{:<width$} {}", preset, preset.description(), width = width);
+                }
+                process::exit(0);
+            },
+            "-e" | "--entropy" => options.print_entropy = true,
+            "-a" | "--answer" | "-p" | "--preset" | "-c" | "--config" => {},
+            "-n" | "--count" => options.count = parse_number(arg, val)?,
+            "-u" | "--unique" => options.uniqueness = parse_uniqueness(val)?,
+            "-w" | "--word-list" => options.word_list = Some(val.to_string()),
+            "-d" | "--dictionary" => options.dictionary = val.to_string(),
+            "--dice" => options.dice = Some(val.to_string()),
+            "--num-words" => options.config.words.num_words = parse_number(arg, val)?,
+            "--min-length" => options.config.words.min_length = parse_number(arg, val)?,
+            "--max-length" => options.config.words.max_length = parse_number(arg, val)?,
+            "--transformations" => options.config.words.transformations = parse_transformations(val)?,
+            "--seperator-type" => options.config.seperator.seperator_type = parse_seperator_type(val)?,
+            "--seperators" => options.config.seperator.seperators = val.chars().collect(),
+            "--digits-before" => options.config.padding_digits.num_before = parse_number(arg, val)?,
+            "--digits-after" => options.config.padding_digits.num_after = parse_number(arg, val)?,
+            "--padding" => options.config.padding_symbols.padding_type = parse_padding_type(val)?,
+            "--padding-character-type" => options.config.padding_symbols.padding_character_type = parse_padding_char_type(val)?,
+            "--padding-chars" => options.config.padding_symbols.padding_chars = val.chars().collect(),
+            _ => return Err(format!("unknown option '{}'", arg)),
+        }
+    }
+
+    return Ok(options);
+}
+
+fn parse_number<T: std::str::FromStr>(option: &str, val: &str) -> Result<T, String> {
+    return val.parse().map_err(|_| format!("invalid number '{}' for option '{}'", val, option));
+}
+
+fn parse_uniqueness(name: &str) -> Result<Uniqueness, String> {
+    return match name {
+        "none" => Ok(Uniqueness::AllowDuplicates),
+        "passwords" => Ok(Uniqueness::UniquePasswords),
+        "words" => Ok(Uniqueness::UniqueWords),
+        _ => Err(format!("unknown uniqueness '{}'", name)),
+    };
+}
+
+fn parse_preset(name: &str) -> Result<Configuration, String> {
+    return name.parse::<Preset>().map(Preset::configuration).map_err(|err| err.to_string());
+}
+
+/// Parses a comma separated list of transformations
+fn parse_transformations(names: &str) -> Result<Vec<WordTransformations>, String> {
+    return names.split(',').filter(|a| !a.is_empty()).map(parse_transformation).collect();
+}
+
+fn parse_transformation(name: &str) -> Result<WordTransformations, String> {
+    return match name {
+        "capitalise-first" => Ok(WordTransformations::CapitaliseFirst),
+        "capitalise-non-first" => Ok(WordTransformations::CapitaliseNonFirst),
+        "lower-case" => Ok(WordTransformations::LowerCase),
+        "upper-case" => Ok(WordTransformations::UpperCase),
+        "alternating-lower-upper" => Ok(WordTransformations::AlternatingLowerUpper),
+        "random-lower-upper" => Ok(WordTransformations::RandomLowerUpper),
+        "random-character-case" => Ok(WordTransformations::RandomCharacterCase),
+        "leetspeak" => Ok(WordTransformations::Leetspeak),
+        "random-leetspeak" => Ok(WordTransformations::RandomLeetspeak),
+        "reverse" => Ok(WordTransformations::Reverse),
+        _ => Err(format!("unknown transformation '{}'", name)),
+    };
+}
+
+fn parse_seperator_type(name: &str) -> Result<SeperatorTypes, String> {
+    return match name {
+        "single-character" => Ok(SeperatorTypes::SingleCharacter),
+        "random-character" => Ok(SeperatorTypes::RandomCharacter),
+        "random-character-per-gap" => Ok(SeperatorTypes::RandomCharacterPerGap),
+        _ => Err(format!("unknown seperator type '{}'", name)),
+    };
+}
+
+fn parse_padding_type(val: &str) -> Result<PaddingTypes, String> {
+    let invalid = || format!("invalid padding '{}', expected fixed:<BEFORE>,<AFTER> or adaptive:<LENGTH>", val);
+
+    if let Some(amounts) = val.strip_prefix("fixed:") {
+        let mut amounts = amounts.splitn(2, ',');
+        let before = amounts.next().and_then(|a| a.parse().ok()).ok_or_else(invalid)?;
+        let after = amounts.next().and_then(|a| a.parse().ok()).ok_or_else(invalid)?;
+        return Ok(PaddingTypes::Fixed(before, after));
+    }
+    if let Some(length) = val.strip_prefix("adaptive:") {
+        let length = length.parse().map_err(|_| invalid())?;
+        return Ok(PaddingTypes::Adaptive(length));
+    }
+    return Err(invalid());
+}
+
+fn parse_padding_char_type(name: &str) -> Result<PaddingCharTypes, String> {
+    return match name {
+        "single-character" => Ok(PaddingCharTypes::SingleCharacter),
+        "random-character" => Ok(PaddingCharTypes::RandomCharacter),
+        "seperator-character" => Ok(PaddingCharTypes::SeperatorCharacter),
+        _ => Err(format!("unknown padding character type '{}'", name)),
+    };
+}
@@ -0,0 +1,138 @@
+//! A builder which starts from a preset configuration and overrides individual settings, so the
+//! nested configuration structs do not have to be written out in full.
+//!
+//! # Example
+//!
+//! ```
+//! use xkcd_pass::Configuration;
+//! use xkcd_pass::configuration::{Preset, WordTransformations};
+//!
+//! let config = Configuration::builder_from(Preset::Web32)
+//!     .num_words(5)
+//!     .transformations(vec![WordTransformations::CapitaliseFirst])
+//!     .seperators(vec!['-'])
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(config.words.num_words, 5);
+//! assert_eq!(config.padding_digits, Configuration::web32().padding_digits);
+//! ```
+
+use super::{Configuration, ConfigError, WordTransformations, SeperatorTypes, PaddingTypes, PaddingCharTypes};
+use super::defaults::Preset;
+use super::super::word_list::WordList;
+
+/// Builds a configuration by overriding the settings of a starting configuration. Create one
+/// with `Configuration::builder` or `Configuration::builder_from`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigurationBuilder {
+    config: Configuration,
+}
+
+impl Configuration {
+    /// Creates a builder which starts from the default configuration.
+    pub fn builder() -> ConfigurationBuilder {
+        return ConfigurationBuilder::new(Configuration::default());
+    }
+
+    /// Creates a builder which starts from the configuration of a preset.
+    pub fn builder_from(preset: Preset) -> ConfigurationBuilder {
+        return ConfigurationBuilder::new(preset.configuration());
+    }
+}
+
+impl ConfigurationBuilder {
+    /// Creates a builder which starts from the given configuration.
+    pub fn new(config: Configuration) -> ConfigurationBuilder {
+        return ConfigurationBuilder { config };
+    }
+
+    /// Sets the number of words in the password
+    pub fn num_words(mut self, num_words: u8) -> ConfigurationBuilder {
+        self.config.words.num_words = num_words;
+        return self;
+    }
+
+    /// Sets the minimum and maximum length of the words in the password
+    pub fn word_length(mut self, min_length: u8, max_length: u8) -> ConfigurationBuilder {
+        self.config.words.min_length = min_length;
+        self.config.words.max_length = max_length;
+        return self;
+    }
+
+    /// Replaces the word transformations, which are applied in order
+    pub fn transformations(mut self, transformations: Vec<WordTransformations>) -> ConfigurationBuilder {
+        self.config.words.transformations = transformations;
+        return self;
+    }
+
+    /// Adds a word transformation after the existing ones
+    pub fn add_transformation(mut self, transformation: WordTransformations) -> ConfigurationBuilder {
+        self.config.words.transformations.push(transformation);
+        return self;
+    }
+
+    /// Sets how the seperator characters are chosen
+    pub fn seperator_type(mut self, seperator_type: SeperatorTypes) -> ConfigurationBuilder {
+        self.config.seperator.seperator_type = seperator_type;
+        return self;
+    }
+
+    /// Sets the characters the seperators are chosen from
+    pub fn seperators(mut self, seperators: Vec<char>) -> ConfigurationBuilder {
+        self.config.seperator.seperators = seperators;
+        return self;
+    }
+
+    /// Sets the number of padding digits before and after the password
+    pub fn padding_digits(mut self, num_before: u8, num_after: u8) -> ConfigurationBuilder {
+        self.config.padding_digits.num_before = num_before;
+        self.config.padding_digits.num_after = num_after;
+        return self;
+    }
+
+    /// Sets the padding style of the password
+    pub fn padding_type(mut self, padding_type: PaddingTypes) -> ConfigurationBuilder {
+        self.config.padding_symbols.padding_type = padding_type;
+        return self;
+    }
+
+    /// Sets how the padding characters are chosen
+    pub fn padding_character_type(mut self, padding_character_type: PaddingCharTypes) -> ConfigurationBuilder {
+        self.config.padding_symbols.padding_character_type = padding_character_type;
+        return self;
+    }
+
+    /// Sets the characters the padding is chosen from
+    pub fn padding_chars(mut self, padding_chars: Vec<char>) -> ConfigurationBuilder {
+        self.config.padding_symbols.padding_chars = padding_chars;
+        return self;
+    }
+
+    /// Builds the configuration, returning an error if its settings are invalid. The word list
+    /// is not known yet, so use `build_for` or `Configuration::validate` to also check that it
+    /// has words of the right length.
+    ///
+    /// ```
+    /// use xkcd_pass::Configuration;
+    /// use xkcd_pass::configuration::ConfigError;
+    ///
+    /// let result = Configuration::builder().word_length(8, 4).build();
+    /// assert_eq!(result, Err(ConfigError::InvalidWordLength { min_length: 8, max_length: 4 }));
+    /// ```
+    pub fn build(self) -> Result<Configuration, ConfigError> {
+        self.config.validate_settings()?;
+        return Ok(self.config);
+    }
+
+    /// Builds the configuration, returning an error if it cannot be used with the word list.
+    pub fn build_for<A: WordList>(self, word_list: &A) -> Result<Configuration, ConfigError> {
+        self.config.validate(word_list)?;
+        return Ok(self.config);
+    }
+}
+
+impl From<Configuration> for ConfigurationBuilder {
+    fn from(config: Configuration) -> ConfigurationBuilder {
+        return ConfigurationBuilder::new(config);
+    }
+}
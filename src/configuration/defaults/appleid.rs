@@ -10,7 +10,7 @@ pub fn configuration_appleid() -> Configuration {
             min_length: 5,
             max_length: 7,
 
-            transformations: WordTransformations::RandomLowerUpper,
+            transformations: vec![WordTransformations::RandomLowerUpper],
         },
         seperator: SeperatorConfiguration {
             seperator_type: SeperatorTypes::RandomCharacter,
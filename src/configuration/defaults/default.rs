@@ -10,7 +10,7 @@ pub fn configuration_default() -> Configuration {
             min_length: 4,
             max_length: 8,
 
-            transformations: WordTransformations::AlternatingLowerUpper,
+            transformations: vec![WordTransformations::AlternatingLowerUpper],
         },
         seperator: SeperatorConfiguration {
             seperator_type: SeperatorTypes::RandomCharacter,
@@ -14,6 +14,19 @@
 //! // Print out the default configuration
 //! println!("{:?}", config);
 //! ```
+//!
+//! A preset can also be chosen with the `Preset` enum, for example to start a
+//! `ConfigurationBuilder`, or looked up by name. Applications can add their own named presets to
+//! a `PresetRegistry`.
+//!
+//! ```
+//! use xkcd_pass::configuration::defaults::Preset;
+//!
+//! let preset: Preset = "web32".parse().unwrap();
+//! for preset in Preset::ALL {
+//!     println!("{}: {}", preset, preset.description());
+//! }
+//! ```
 
 pub mod default;
 pub mod appleid;
@@ -22,6 +35,10 @@ pub mod xkcd;
 pub mod web16;
 pub mod web32;
 pub mod wifi;
+pub mod securityq;
+pub mod weblong;
+mod preset;
+mod registry;
 
 pub use self::default::configuration_default;
 pub use self::appleid::configuration_appleid;
@@ -30,3 +47,7 @@ pub use self::xkcd::configuration_xkcd;
 pub use self::web16::configuration_web16;
 pub use self::web32::configuration_web32;
 pub use self::wifi::configuration_wifi;
+pub use self::securityq::configuration_securityq;
+pub use self::weblong::configuration_weblong;
+pub use self::preset::{Preset, UnknownPresetError};
+pub use self::registry::{PresetRegistry, RegisteredPreset};
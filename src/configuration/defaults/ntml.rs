@@ -10,7 +10,7 @@ pub fn configuration_ntml() -> Configuration {
             min_length: 5,
             max_length: 5,
 
-            transformations: WordTransformations::CapitaliseNonFirst,
+            transformations: vec![WordTransformations::CapitaliseNonFirst],
         },
         seperator: SeperatorConfiguration {
             seperator_type: SeperatorTypes::RandomCharacter,
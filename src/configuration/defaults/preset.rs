@@ -0,0 +1,120 @@
+//! The `Preset` enum, which names the preset configurations.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use super::super::Configuration;
+use super::{configuration_default, configuration_appleid, configuration_ntml, configuration_xkcd, configuration_web16,
+            configuration_web32, configuration_wifi, configuration_securityq, configuration_weblong};
+
+/// The preset configurations. A preset is written by its lowercase name, such as `web32`, when
+/// parsed, displayed or serialized
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Preset {
+    /// The default configuration, see `configuration_default`
+    Default,
+    /// The Apple ID configuration, see `configuration_appleid`
+    AppleId,
+    /// The NTML configuration, see `configuration_ntml`
+    Ntml,
+    /// The XKCD configuration, see `configuration_xkcd`
+    Xkcd,
+    /// The web16 configuration, see `configuration_web16`
+    Web16,
+    /// The web32 configuration, see `configuration_web32`
+    Web32,
+    /// The wifi configuration, see `configuration_wifi`
+    Wifi,
+    /// The security question configuration, see `configuration_securityq`
+    SecurityQ,
+    /// The long web configuration, which is not an xkpasswd preset, see `configuration_weblong`
+    WebLong,
+}
+
+impl Preset {
+    /// Every preset, in the order they are listed in the documentation
+    pub const ALL: [Preset; 9] = [Preset::Default, Preset::AppleId, Preset::Ntml, Preset::Xkcd, Preset::Web16, Preset::Web32, Preset::Wifi,
+                                  Preset::SecurityQ, Preset::WebLong];
+
+    /// Creates the configuration of the preset.
+    ///
+    /// ```
+    /// use xkcd_pass::Configuration;
+    /// use xkcd_pass::configuration::Preset;
+    ///
+    /// assert_eq!(Preset::Web32.configuration(), Configuration::web32());
+    /// ```
+    pub fn configuration(self) -> Configuration {
+        return match self {
+            Preset::Default => configuration_default(),
+            Preset::AppleId => configuration_appleid(),
+            Preset::Ntml => configuration_ntml(),
+            Preset::Xkcd => configuration_xkcd(),
+            Preset::Web16 => configuration_web16(),
+            Preset::Web32 => configuration_web32(),
+            Preset::Wifi => configuration_wifi(),
+            Preset::SecurityQ => configuration_securityq(),
+            Preset::WebLong => configuration_weblong(),
+        };
+    }
+
+    /// The lowercase name of the preset, such as `web32`
+    pub fn name(self) -> &'static str {
+        return match self {
+            Preset::Default => "default",
+            Preset::AppleId => "appleid",
+            Preset::Ntml => "ntml",
+            Preset::Xkcd => "xkcd",
+            Preset::Web16 => "web16",
+            Preset::Web32 => "web32",
+            Preset::Wifi => "wifi",
+            Preset::SecurityQ => "securityq",
+            Preset::WebLong => "weblong",
+        };
+    }
+
+    /// A short description of the passwords the preset generates
+    pub fn description(self) -> &'static str {
+        return match self {
+            Preset::Default => "3 words with alternating case, 2 digits and 2 symbols at each end",
+            Preset::AppleId => "3 words with random case, 2 digits and a symbol at each end, for Apple IDs",
+            Preset::Ntml => "2 five letter words, a digit and a symbol, 14 characters for Windows NTLMv1",
+            Preset::Xkcd => "4 words with random case joined by dashes, as in the XKCD comic",
+            Preset::Web16 => "3 four letter words with random case and a symbol at each end, for 16 character limits",
+            Preset::Web32 => "4 short words with alternating case, 2 digits and a symbol at each end, for 32 character limits",
+            Preset::Wifi => "6 words and 8 digits padded to 63 characters, the longest WPA2 key",
+            Preset::SecurityQ => "6 lowercase words separated by spaces and ending in '.', '!' or '?', for security questions",
+            Preset::WebLong => "6 words with alternating case, 2 digits and a symbol at each end, for 64 character limits",
+        };
+    }
+}
+
+impl fmt::Display for Preset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return f.pad(self.name());
+    }
+}
+
+impl FromStr for Preset {
+    type Err = UnknownPresetError;
+
+    /// Finds the preset with the name, ignoring case
+    fn from_str(name: &str) -> Result<Preset, UnknownPresetError> {
+        return Preset::ALL.iter().cloned().find(|a| a.name().eq_ignore_ascii_case(name))
+            .ok_or_else(|| UnknownPresetError(name.to_string()));
+    }
+}
+
+/// No preset has the name. Contains the name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownPresetError(pub String);
+
+impl fmt::Display for UnknownPresetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "unknown preset '{}'", self.0);
+    }
+}
+
+impl Error for UnknownPresetError {}
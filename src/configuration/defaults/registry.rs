@@ -0,0 +1,88 @@
+//! A registry of named configurations, which holds the presets and any configurations an
+//! application registers.
+
+use std::slice;
+
+use super::super::{Configuration, ConfigError};
+use super::{Preset, UnknownPresetError};
+
+/// A named configuration in a `PresetRegistry`
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegisteredPreset {
+    /// The name the configuration is looked up by
+    pub name: String,
+    /// A short description of the passwords the configuration generates
+    pub description: String,
+    /// The configuration
+    pub configuration: Configuration,
+}
+
+/// Named configurations which can be looked up by name, ignoring case. A new registry holds the
+/// presets, and applications can register their own configurations.
+///
+/// ```
+/// use xkcd_pass::Configuration;
+/// use xkcd_pass::configuration::PresetRegistry;
+///
+/// let mut registry = PresetRegistry::new();
+/// let config = Configuration::builder().num_words(6).build().unwrap();
+/// registry.register("long", "6 words", config.clone()).unwrap();
+///
+/// assert_eq!(registry.configuration("long"), Ok(config));
+/// assert_eq!(registry.configuration("web32"), Ok(Configuration::web32()));
+/// for preset in registry.iter() {
+///     println!("{}: {}", preset.name, preset.description);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PresetRegistry {
+    presets: Vec<RegisteredPreset>,
+}
+
+impl PresetRegistry {
+    /// Creates a registry which holds the presets
+    pub fn new() -> PresetRegistry {
+        let presets = Preset::ALL.iter().map(|a| {
+            RegisteredPreset { name: a.name().to_string(), description: a.description().to_string(), configuration: a.configuration() }
+        }).collect();
+        return PresetRegistry { presets };
+    }
+
+    /// Creates a registry without any configurations
+    pub fn empty() -> PresetRegistry {
+        return PresetRegistry { presets: Vec::new() };
+    }
+
+    /// Registers a named configuration, replacing any configuration with the same name. Returns
+    /// an error if the settings of the configuration are invalid.
+    pub fn register(&mut self, name: &str, description: &str, configuration: Configuration) -> Result<(), ConfigError> {
+        configuration.validate_settings()?;
+        let preset = RegisteredPreset { name: name.to_string(), description: description.to_string(), configuration };
+        match self.presets.iter_mut().find(|a| a.name.eq_ignore_ascii_case(name)) {
+            Some(existing) => *existing = preset,
+            None => self.presets.push(preset),
+        }
+        return Ok(());
+    }
+
+    /// Finds the configuration with the name, ignoring case
+    pub fn get(&self, name: &str) -> Option<&RegisteredPreset> {
+        return self.presets.iter().find(|a| a.name.eq_ignore_ascii_case(name));
+    }
+
+    /// Creates a copy of the configuration with the name, ignoring case
+    pub fn configuration(&self, name: &str) -> Result<Configuration, UnknownPresetError> {
+        return self.get(name).map(|a| a.configuration.clone()).ok_or_else(|| UnknownPresetError(name.to_string()));
+    }
+
+    /// Iterates over the configurations in the order they were registered, after the presets
+    pub fn iter(&self) -> slice::Iter<'_, RegisteredPreset> {
+        return self.presets.iter();
+    }
+}
+
+impl Default for PresetRegistry {
+    fn default() -> PresetRegistry {
+        return PresetRegistry::new();
+    }
+}
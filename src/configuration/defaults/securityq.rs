@@ -0,0 +1,31 @@
+//! The security question configuration for the password generator. The passwords look like
+//! sentences, so they can be used as answers to security questions.
+
+use super::super::*;
+
+pub fn configuration_securityq() -> Configuration {
+
+    return Configuration {
+        words: WordConfiguration {
+            num_words: 6,
+            min_length: 4,
+            max_length: 8,
+
+            transformations: vec![WordTransformations::LowerCase],
+        },
+        seperator: SeperatorConfiguration {
+            seperator_type: SeperatorTypes::SingleCharacter,
+            seperators: vec![' '],
+        },
+        padding_digits: PaddingDigitConfiguration {
+            num_before: 0,
+            num_after: 0,
+        },
+        padding_symbols: PaddingSymbolConfiguration {
+            padding_type: PaddingTypes::Fixed(0, 1),
+            padding_character_type: PaddingCharTypes::RandomCharacter,
+            padding_chars: vec!['.', '!', '?'],
+        },
+    };
+
+}
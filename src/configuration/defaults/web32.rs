@@ -10,7 +10,7 @@ pub fn configuration_web32() -> Configuration {
             min_length: 4,
             max_length: 5,
 
-            transformations: WordTransformations::AlternatingLowerUpper,
+            transformations: vec![WordTransformations::AlternatingLowerUpper],
         },
         seperator: SeperatorConfiguration {
             seperator_type: SeperatorTypes::RandomCharacter,
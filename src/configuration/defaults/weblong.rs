@@ -0,0 +1,31 @@
+//! The long web configuration for the password generator. It is not one of the xkpasswd presets,
+//! but follows web32 for websites which accept passwords of up to 64 characters.
+
+use super::super::*;
+
+pub fn configuration_weblong() -> Configuration {
+
+    return Configuration {
+        words: WordConfiguration {
+            num_words: 6,
+            min_length: 4,
+            max_length: 8,
+
+            transformations: vec![WordTransformations::AlternatingLowerUpper],
+        },
+        seperator: SeperatorConfiguration {
+            seperator_type: SeperatorTypes::RandomCharacter,
+            seperators: vec!['-', '+', '=', '.', '*', '_', '|', '~', ','],
+        },
+        padding_digits: PaddingDigitConfiguration {
+            num_before: 2,
+            num_after: 2,
+        },
+        padding_symbols: PaddingSymbolConfiguration {
+            padding_type: PaddingTypes::Fixed(1, 1),
+            padding_character_type: PaddingCharTypes::RandomCharacter,
+            padding_chars: vec!['!', '@', '$', '%', '^', '&', '*', '+', '=', ':', '|', '~', '?'],
+        },
+    };
+
+}
@@ -10,7 +10,7 @@ pub fn configuration_wifi() -> Configuration {
             min_length: 4,
             max_length: 8,
 
-            transformations: WordTransformations::RandomLowerUpper,
+            transformations: vec![WordTransformations::RandomLowerUpper],
         },
         seperator: SeperatorConfiguration {
             seperator_type: SeperatorTypes::RandomCharacter,
@@ -0,0 +1,262 @@
+//! Reading and writing configurations as JSON, TOML or YAML.
+//!
+//! Each format is behind a cargo feature of the same name: `json`, `toml` and `yaml`.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(feature = "json")]
+//! # fn main() {
+//! use xkcd_pass::Configuration;
+//!
+//! let json = Configuration::web32().to_json_string().unwrap();
+//! let config = Configuration::from_json_str(&json).unwrap();
+//! # }
+//! # #[cfg(not(feature = "json"))]
+//! # fn main() {}
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+#[cfg(feature = "json")]
+use serde_json;
+#[cfg(feature = "toml")]
+use toml;
+#[cfg(feature = "yaml")]
+use serde_yaml;
+
+use super::Configuration;
+
+/// The file formats a configuration can be read from and written to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    /// JSON, requires the `json` feature
+    Json,
+    /// TOML, requires the `toml` feature
+    Toml,
+    /// YAML, requires the `yaml` feature
+    Yaml,
+}
+
+impl ConfigFormat {
+    /// Guesses the format of a configuration file from its extension. The extensions `json`,
+    /// `toml`, `yaml` and `yml` are recognised.
+    ///
+    /// ```
+    /// use xkcd_pass::configuration::ConfigFormat;
+    ///
+    /// assert_eq!(ConfigFormat::from_path("policy.toml"), Some(ConfigFormat::Toml));
+    /// assert_eq!(ConfigFormat::from_path("policy.txt"), None);
+    /// ```
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<ConfigFormat> {
+        let extension = path.as_ref().extension().and_then(|a| a.to_str()).map(|a| a.to_lowercase());
+        return match extension.as_deref() {
+            Some("json") => Some(ConfigFormat::Json),
+            Some("toml") => Some(ConfigFormat::Toml),
+            Some("yaml") | Some("yml") => Some(ConfigFormat::Yaml),
+            _ => None,
+        };
+    }
+}
+
+impl fmt::Display for ConfigFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match *self {
+            ConfigFormat::Json => write!(f, "JSON"),
+            ConfigFormat::Toml => write!(f, "TOML"),
+            ConfigFormat::Yaml => write!(f, "YAML"),
+        };
+    }
+}
+
+/// The reasons a configuration could not be read or written
+#[derive(Debug)]
+pub enum ConfigFileError {
+    /// The file could not be read or written
+    Io(io::Error),
+    /// The format could not be determined from the file extension
+    UnknownFormat,
+    /// The crate was compiled without the feature for the format
+    FormatNotEnabled(ConfigFormat),
+    /// The JSON could not be parsed or written
+    #[cfg(feature = "json")]
+    Json(serde_json::Error),
+    /// The TOML could not be parsed
+    #[cfg(feature = "toml")]
+    TomlDeserialize(toml::de::Error),
+    /// The TOML could not be written
+    #[cfg(feature = "toml")]
+    TomlSerialize(toml::ser::Error),
+    /// The YAML could not be parsed or written
+    #[cfg(feature = "yaml")]
+    Yaml(serde_yaml::Error),
+}
+
+impl fmt::Display for ConfigFileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match *self {
+            ConfigFileError::Io(ref err) => write!(f, "{}", err),
+            ConfigFileError::UnknownFormat => write!(f, "the configuration format could not be determined from the file extension"),
+            ConfigFileError::FormatNotEnabled(format) => write!(f, "support for {} configurations was not enabled", format),
+            #[cfg(feature = "json")]
+            ConfigFileError::Json(ref err) => write!(f, "invalid JSON configuration: {}", err),
+            #[cfg(feature = "toml")]
+            ConfigFileError::TomlDeserialize(ref err) => write!(f, "invalid TOML configuration: {}", err),
+            #[cfg(feature = "toml")]
+            ConfigFileError::TomlSerialize(ref err) => write!(f, "could not write TOML configuration: {}", err),
+            #[cfg(feature = "yaml")]
+            ConfigFileError::Yaml(ref err) => write!(f, "invalid YAML configuration: {}", err),
+        };
+    }
+}
+
+impl Error for ConfigFileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        return match *self {
+            ConfigFileError::Io(ref err) => Some(err),
+            ConfigFileError::UnknownFormat | ConfigFileError::FormatNotEnabled(_) => None,
+            #[cfg(feature = "json")]
+            ConfigFileError::Json(ref err) => Some(err),
+            #[cfg(feature = "toml")]
+            ConfigFileError::TomlDeserialize(ref err) => Some(err),
+            #[cfg(feature = "toml")]
+            ConfigFileError::TomlSerialize(ref err) => Some(err),
+            #[cfg(feature = "yaml")]
+            ConfigFileError::Yaml(ref err) => Some(err),
+        };
+    }
+}
+
+impl From<io::Error> for ConfigFileError {
+    fn from(err: io::Error) -> ConfigFileError {
+        return ConfigFileError::Io(err);
+    }
+}
+
+impl Configuration {
+    /// Reads a configuration from a JSON string.
+    #[cfg(feature = "json")]
+    pub fn from_json_str(s: &str) -> Result<Configuration, ConfigFileError> {
+        return serde_json::from_str(s).map_err(ConfigFileError::Json);
+    }
+
+    /// Writes the configuration as a pretty printed JSON string.
+    #[cfg(feature = "json")]
+    pub fn to_json_string(&self) -> Result<String, ConfigFileError> {
+        return serde_json::to_string_pretty(self).map_err(ConfigFileError::Json);
+    }
+
+    /// Reads a configuration from a TOML string.
+    ///
+    /// ```
+    /// # #[cfg(feature = "toml")]
+    /// # fn main() {
+    /// use xkcd_pass::Configuration;
+    ///
+    /// let config = Configuration::from_toml_str(r#"
+    ///     [words]
+    ///     num_words = 4
+    ///     min_length = 4
+    ///     max_length = 8
+    ///     transformations = ["CapitaliseFirst", "Leetspeak"]
+    ///
+    ///     [seperator]
+    ///     seperator_type = "SingleCharacter"
+    ///     seperators = ["-"]
+    ///
+    ///     [padding_digits]
+    ///     num_before = 0
+    ///     num_after = 2
+    ///
+    ///     [padding_symbols]
+    ///     padding_type = { Fixed = [0, 1] }
+    ///     padding_character_type = "RandomCharacter"
+    ///     padding_chars = ["!", "?"]
+    /// "#).unwrap();
+    /// assert_eq!(config.words.num_words, 4);
+    /// # }
+    /// # #[cfg(not(feature = "toml"))]
+    /// # fn main() {}
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn from_toml_str(s: &str) -> Result<Configuration, ConfigFileError> {
+        return toml::from_str(s).map_err(ConfigFileError::TomlDeserialize);
+    }
+
+    /// Writes the configuration as a pretty printed TOML string.
+    #[cfg(feature = "toml")]
+    pub fn to_toml_string(&self) -> Result<String, ConfigFileError> {
+        return toml::to_string_pretty(self).map_err(ConfigFileError::TomlSerialize);
+    }
+
+    /// Reads a configuration from a YAML string.
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml_str(s: &str) -> Result<Configuration, ConfigFileError> {
+        return serde_yaml::from_str(s).map_err(ConfigFileError::Yaml);
+    }
+
+    /// Writes the configuration as a YAML string.
+    #[cfg(feature = "yaml")]
+    pub fn to_yaml_string(&self) -> Result<String, ConfigFileError> {
+        return serde_yaml::to_string(self).map_err(ConfigFileError::Yaml);
+    }
+
+    /// Reads a configuration from a string in the given format. Returns
+    /// `ConfigFileError::FormatNotEnabled` if the feature for the format is disabled.
+    #[cfg_attr(not(any(feature = "json", feature = "toml", feature = "yaml")), allow(unused_variables))]
+    pub fn from_str_with_format(s: &str, format: ConfigFormat) -> Result<Configuration, ConfigFileError> {
+        return match format {
+            #[cfg(feature = "json")]
+            ConfigFormat::Json => Configuration::from_json_str(s),
+            #[cfg(feature = "toml")]
+            ConfigFormat::Toml => Configuration::from_toml_str(s),
+            #[cfg(feature = "yaml")]
+            ConfigFormat::Yaml => Configuration::from_yaml_str(s),
+            #[allow(unreachable_patterns)]
+            _ => Err(ConfigFileError::FormatNotEnabled(format)),
+        };
+    }
+
+    /// Writes the configuration as a string in the given format. Returns
+    /// `ConfigFileError::FormatNotEnabled` if the feature for the format is disabled.
+    pub fn to_string_with_format(&self, format: ConfigFormat) -> Result<String, ConfigFileError> {
+        return match format {
+            #[cfg(feature = "json")]
+            ConfigFormat::Json => self.to_json_string(),
+            #[cfg(feature = "toml")]
+            ConfigFormat::Toml => self.to_toml_string(),
+            #[cfg(feature = "yaml")]
+            ConfigFormat::Yaml => self.to_yaml_string(),
+            #[allow(unreachable_patterns)]
+            _ => Err(ConfigFileError::FormatNotEnabled(format)),
+        };
+    }
+
+    /// Reads a configuration from a file. The format is determined by the file extension, see
+    /// `ConfigFormat::from_path`.
+    ///
+    /// ```no_run
+    /// use xkcd_pass::Configuration;
+    ///
+    /// let config = Configuration::from_file("password_policy.toml").unwrap();
+    /// ```
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Configuration, ConfigFileError> {
+        let format = ConfigFormat::from_path(&path).ok_or(ConfigFileError::UnknownFormat)?;
+        let mut contents = String::new();
+        File::open(path)?.read_to_string(&mut contents)?;
+        return Configuration::from_str_with_format(&contents, format);
+    }
+
+    /// Writes the configuration to a file. The format is determined by the file extension, see
+    /// `ConfigFormat::from_path`.
+    pub fn to_file<P: AsRef<Path>>(&self, path: P) -> Result<(), ConfigFileError> {
+        let format = ConfigFormat::from_path(&path).ok_or(ConfigFileError::UnknownFormat)?;
+        let contents = self.to_string_with_format(format)?;
+        File::create(path)?.write_all(contents.as_bytes())?;
+        return Ok(());
+    }
+}
@@ -8,18 +8,35 @@
 //!
 //! ```
 //! use xkcd_pass::Configuration;
+//! use xkcd_pass::configuration::Preset;
 //!
 //! // Make a new default configuration
 //! let default_config = Configuration::default();
 //! // Make a new xkcd configuration
 //! let xkcd_config = Configuration::xkcd();
+//! // Change some settings of a preset
+//! let builder_config = Configuration::builder_from(Preset::Xkcd).num_words(5).build().unwrap();
 //! ```
 
+use serde::{Deserialize, Deserializer};
+
 pub mod defaults;
+mod validation;
+mod file;
+mod xkpasswd;
+mod builder;
+#[cfg(test)]
+mod tests;
+
+pub use self::validation::{ConfigError, MAX_PADDING_DIGITS};
+pub use self::file::{ConfigFormat, ConfigFileError};
+pub use self::xkpasswd::{XkpasswdConfig, XkpasswdCaseTransform, XkpasswdPaddingType, XkpasswdError};
+pub use self::builder::ConfigurationBuilder;
+pub use self::defaults::{Preset, PresetRegistry, RegisteredPreset, UnknownPresetError};
 
 /// The base configuration struct for the password generator. Pass this configuration to the
 /// generator to create a password.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Configuration {
     /// The configuration for the quantity and style of words generated
     pub words: WordConfiguration,
@@ -41,6 +58,7 @@ impl Configuration {
     /// // Print out the default configuration
     /// println!("{:?}", config);
     /// ```
+    #[allow(clippy::should_implement_trait)]
     pub fn default() -> Configuration {
         return defaults::configuration_default();
     }
@@ -122,10 +140,37 @@ impl Configuration {
     pub fn wifi() -> Configuration {
         return defaults::configuration_wifi();
     }
+
+    /// Creates a new configuration with the security question settings loaded in.
+    ///
+    /// ```
+    /// use xkcd_pass::configuration::Configuration;
+    ///
+    /// let config = Configuration::securityq();
+    /// // Print out the security question configuration
+    /// println!("{:?}", config);
+    /// ```
+    pub fn securityq() -> Configuration {
+        return defaults::configuration_securityq();
+    }
+
+    /// Creates a new configuration with the long web settings loaded in. This is not one of the
+    /// xkpasswd presets.
+    ///
+    /// ```
+    /// use xkcd_pass::configuration::Configuration;
+    ///
+    /// let config = Configuration::weblong();
+    /// // Print out the long web configuration
+    /// println!("{:?}", config);
+    /// ```
+    pub fn weblong() -> Configuration {
+        return defaults::configuration_weblong();
+    }
 }
 
 /// The configuration for the quantity and style of words generated
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WordConfiguration {
     /// The number of words that should be in the password
     pub num_words: u8,
@@ -134,15 +179,35 @@ pub struct WordConfiguration {
     /// The maximum length of the words in the password
     pub max_length: u8,
 
-    /// The transformations that should be applied to the words
-    pub transformations: WordTransformations,
+    /// The transformations that should be applied to the words, in order. The words are always
+    /// lower case before the first transformation is applied. A single transformation can also
+    /// be given when deserializing a configuration
+    #[serde(deserialize_with = "deserialize_transformations")]
+    pub transformations: Vec<WordTransformations>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Accepts either a single transformation, as used before transformations could be composed, or
+/// a list of transformations
+fn deserialize_transformations<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<WordTransformations>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(WordTransformations),
+        Many(Vec<WordTransformations>),
+    }
+
+    return match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(transformation) => Ok(vec![transformation]),
+        OneOrMany::Many(transformations) => Ok(transformations),
+    };
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum WordTransformations {
     /// Capitalise the first letter of every word. i.e `Random` and `Word`
     CapitaliseFirst,
-    /// Capitalise all but the first letter of every word. i.e `rANDOM` and `wORD`
+    /// Capitalise all but the first letter of every word. i.e `rANDOM` and `wORD`. This is the
+    /// `INVERT` case transformation in xkpasswd
     CapitaliseNonFirst,
     /// Convert all the words into lowercase. i.e `lower` and `case`
     LowerCase,
@@ -152,10 +217,21 @@ pub enum WordTransformations {
     AlternatingLowerUpper,
     /// Randomly convert each word in the password to uppercase or lowercase. i.e `ArandomWORDPASSWORD`
     RandomLowerUpper,
+    /// Randomly convert each letter in the password to uppercase or lowercase. i.e `rAnDOm` and
+    /// `WoRd`
+    RandomCharacterCase,
+    /// Replace every letter which has a leetspeak substitution with its substitute. i.e `r4nd0m`
+    /// and `w0rd`. The substitutions are listed in `generator::LEETSPEAK_SUBSTITUTIONS`
+    Leetspeak,
+    /// Randomly replace each letter which has a leetspeak substitution with its substitute.
+    /// i.e `r4ndom` and `w0rd`
+    RandomLeetspeak,
+    /// Reverse the letters of every word. i.e `modnar` and `drow`
+    Reverse,
 }
 
 /// The configuration for the seperator characters between words
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SeperatorConfiguration {
     /// The method in which the seperator chosen during password generation
     pub seperator_type: SeperatorTypes,
@@ -163,17 +239,22 @@ pub struct SeperatorConfiguration {
     pub seperators: Vec<char>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum SeperatorTypes {
     /// Use the same character (the first character in the seperators vector) for the seperator
     /// between all of the words
     SingleCharacter,
-    /// Use a random character for the seperator between each word
+    /// Choose one random character from the seperators vector and use it for the seperator
+    /// between all of the words
     RandomCharacter,
+    /// Choose a new random character from the seperators vector for every seperator, including
+    /// the seperators between the words and the padding digits. When the padding uses the
+    /// seperator character, it is chosen independently of the seperators
+    RandomCharacterPerGap,
 }
 
 /// The configuration for the padding digits before and after the password
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PaddingDigitConfiguration {
     /// The number of digits to pad before the password
     pub num_before: u8,
@@ -182,7 +263,7 @@ pub struct PaddingDigitConfiguration {
 }
 
 /// The configuration for the padding symbols before and after the password
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PaddingSymbolConfiguration {
     /// The padding style to use for the password
     pub padding_type: PaddingTypes,
@@ -193,7 +274,7 @@ pub struct PaddingSymbolConfiguration {
 }
 
 /// The padding style to use for the password
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum PaddingTypes {
     /// Pad the password to the length given. If the password is only 10 characters long and this
     /// is set to 16, 6 extra characters of padding will be added to the end of the password
@@ -205,7 +286,7 @@ pub enum PaddingTypes {
 }
 
 /// The method in which the padding characters are chosen
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum PaddingCharTypes {
     /// Only a single character will be used for the padding. The first character in the
     /// `padding_chars` vector in `PaddingSymbolConfiguration` is used
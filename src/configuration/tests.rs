@@ -0,0 +1,219 @@
+use super::{Configuration, ConfigError, ConfigFormat, PaddingCharTypes, PaddingTypes, Preset, PresetRegistry, UnknownPresetError, WordTransformations};
+use super::super::generator::generate_password;
+use super::super::word_list::SimpleEnglish;
+
+#[test]
+pub fn test_preset_names() {
+    for preset in Preset::ALL.iter() {
+        assert_eq!(preset.name().parse(), Ok(*preset));
+        assert!(!preset.description().is_empty());
+    }
+    assert_eq!("AppleID".parse(), Ok(Preset::AppleId));
+    assert_eq!("web64".parse::<Preset>(), Err(UnknownPresetError("web64".to_string())));
+}
+
+#[test]
+pub fn test_preset_registry() {
+    let mut registry = PresetRegistry::new();
+    assert_eq!(registry.iter().count(), Preset::ALL.len());
+    assert_eq!(registry.configuration("Wifi"), Ok(Configuration::wifi()));
+
+    let config = Configuration::builder_from(Preset::Xkcd).num_words(6).build().unwrap();
+    registry.register("long-xkcd", "6 words", config.clone()).unwrap();
+    assert_eq!(registry.configuration("long-xkcd"), Ok(config.clone()));
+    assert_eq!(registry.iter().last().unwrap().name, "long-xkcd");
+
+    // Registering a name again replaces the configuration
+    registry.register("XKCD", "Replaced", config.clone()).unwrap();
+    assert_eq!(registry.configuration("xkcd"), Ok(config));
+    assert_eq!(registry.iter().count(), Preset::ALL.len() + 1);
+
+    let mut invalid = Configuration::default();
+    invalid.seperator.seperators.clear();
+    assert_eq!(registry.register("invalid", "", invalid), Err(ConfigError::NoSeperators));
+    assert_eq!(registry.configuration("invalid"), Err(UnknownPresetError("invalid".to_string())));
+}
+
+fn presets() -> Vec<Configuration> {
+    return vec![Configuration::default(), Configuration::appleid(), Configuration::ntml(),
+                Configuration::xkcd(), Configuration::web16(), Configuration::web32(),
+                Configuration::wifi(), Configuration::securityq(), Configuration::weblong()];
+}
+
+#[test]
+pub fn test_presets_are_valid() {
+    let word_list = SimpleEnglish::new();
+
+    for config in presets() {
+        assert_eq!(config.validate(&word_list), Ok(()));
+    }
+}
+
+#[test]
+pub fn test_securityq_preset() {
+    let password = generate_password(&Configuration::securityq(), &SimpleEnglish::new());
+    let (answer, punctuation) = password.split_at(password.len() - 1);
+    assert!([".", "!", "?"].contains(&punctuation));
+    assert_eq!(answer.split(' ').count(), 6);
+    assert!(answer.chars().all(|a| a == ' ' || a.is_lowercase()));
+}
+
+#[test]
+pub fn test_invalid_configurations() {
+    let word_list = SimpleEnglish::new();
+
+    let mut config = Configuration::default();
+    config.words.min_length = 8;
+    config.words.max_length = 4;
+    assert_eq!(config.validate(&word_list), Err(ConfigError::InvalidWordLength { min_length: 8, max_length: 4 }));
+
+    let mut config = Configuration::default();
+    config.words.min_length = 50;
+    config.words.max_length = 60;
+    assert_eq!(config.validate(&word_list), Err(ConfigError::NoMatchingWords { min_length: 50, max_length: 60 }));
+
+    let mut config = Configuration::default();
+    config.padding_symbols.padding_chars.clear();
+    assert_eq!(config.validate(&word_list), Err(ConfigError::NoPaddingCharacters));
+    // The padding characters are not needed when the seperator character is used
+    config.padding_symbols.padding_character_type = PaddingCharTypes::SeperatorCharacter;
+    assert_eq!(config.validate(&word_list), Ok(()));
+
+    let mut config = Configuration::default();
+    config.padding_digits.num_after = 20;
+    assert_eq!(config.validate(&word_list), Err(ConfigError::TooManyPaddingDigits(20)));
+    config.padding_digits.num_after = 19;
+    assert_eq!(config.validate(&word_list), Ok(()));
+}
+
+#[test]
+pub fn test_builder() {
+    let word_list = SimpleEnglish::new();
+
+    let config = Configuration::builder_from(Preset::Xkcd)
+        .num_words(5)
+        .add_transformation(WordTransformations::Leetspeak)
+        .padding_type(PaddingTypes::Adaptive(32))
+        .build()
+        .unwrap();
+    let mut expected = Configuration::xkcd();
+    expected.words.num_words = 5;
+    expected.words.transformations.push(WordTransformations::Leetspeak);
+    expected.padding_symbols.padding_type = PaddingTypes::Adaptive(32);
+    assert_eq!(config, expected);
+
+    assert_eq!(Configuration::builder().build(), Ok(Configuration::default()));
+    assert_eq!(Configuration::builder().seperators(Vec::new()).build(), Err(ConfigError::NoSeperators));
+    assert_eq!(Configuration::builder().word_length(50, 60).build_for(&word_list), Err(ConfigError::NoMatchingWords { min_length: 50, max_length: 60 }));
+}
+
+#[test]
+pub fn test_file_format_round_trip() {
+    let formats = vec![ConfigFormat::Json, ConfigFormat::Toml, ConfigFormat::Yaml];
+
+    for config in presets() {
+        for &format in &formats {
+            match config.to_string_with_format(format) {
+                Ok(contents) => {
+                    let parsed = Configuration::from_str_with_format(&contents, format).unwrap();
+                    assert_eq!(parsed, config);
+                },
+                // The feature for the format is disabled
+                Err(_) => assert!(Configuration::from_str_with_format("", format).is_err()),
+            }
+        }
+    }
+}
+
+#[test]
+pub fn test_xkpasswd_round_trip() {
+    for config in presets() {
+        let xkpasswd = config.to_xkpasswd().unwrap();
+        assert_eq!(Configuration::from_xkpasswd(&xkpasswd).unwrap(), config);
+    }
+}
+
+#[test]
+#[cfg(feature = "json")]
+pub fn test_xkpasswd_json_import() {
+    use super::{PaddingTypes, SeperatorTypes, WordTransformations, XkpasswdError};
+
+    // The WEB32 preset exported from xkpasswd.net
+    let config = Configuration::from_xkpasswd_json(r#"{
+        "num_words": 4,
+        "word_length_min": 4,
+        "word_length_max": 5,
+        "case_transform": "ALTERNATE",
+        "separator_character": "RANDOM",
+        "separator_alphabet": ["-", "+", "=", ".", "*", "_", "|", "~", ","],
+        "padding_digits_before": 2,
+        "padding_digits_after": 2,
+        "padding_type": "FIXED",
+        "padding_character": "RANDOM",
+        "symbol_alphabet": ["!", "@", "$", "%", "^", "&", "*", "+", "=", ":", "|", "~", "?"],
+        "padding_characters_before": 1,
+        "padding_characters_after": 1,
+        "random_increment": "AUTO",
+        "allow_accents": 0
+    }"#).unwrap();
+    assert_eq!(config, Configuration::web32());
+    assert_eq!(config.words.transformations, vec![WordTransformations::AlternatingLowerUpper]);
+    assert_eq!(config.seperator.seperator_type, SeperatorTypes::RandomCharacter);
+    assert_eq!(config.padding_symbols.padding_type, PaddingTypes::Fixed(1, 1));
+
+    let unsupported = Configuration::from_xkpasswd_json(r#"{
+        "num_words": 3,
+        "word_length_min": 4,
+        "word_length_max": 8,
+        "case_transform": "NONE",
+        "separator_character": "-",
+        "padding_digits_before": 0,
+        "padding_digits_after": 0,
+        "padding_type": "NONE"
+    }"#);
+    match unsupported {
+        Err(XkpasswdError::Unsupported { setting, .. }) => assert_eq!(setting, "case_transform"),
+        other => panic!("unexpected result {:?}", other),
+    }
+
+    // xkpasswd truncates passwords which are longer than the adaptive padding length
+    let truncated = Configuration::from_xkpasswd_json(r#"{
+        "num_words": 3,
+        "word_length_min": 4,
+        "word_length_max": 8,
+        "case_transform": "LOWER",
+        "separator_character": "-",
+        "padding_digits_before": 0,
+        "padding_digits_after": 0,
+        "padding_type": "ADAPTIVE",
+        "padding_character": "!",
+        "pad_to_length": 20
+    }"#);
+    match truncated {
+        Err(XkpasswdError::Unsupported { setting, .. }) => assert_eq!(setting, "pad_to_length"),
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[test]
+pub fn test_xkpasswd_export_without_truncation() {
+    use super::{PaddingTypes, XkpasswdError};
+
+    let mut config = Configuration::xkcd();
+    config.padding_symbols.padding_type = PaddingTypes::Adaptive(20);
+    match config.to_xkpasswd() {
+        Err(XkpasswdError::Unsupported { setting, .. }) => assert_eq!(setting, "pad_to_length"),
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[test]
+#[cfg(feature = "json")]
+pub fn test_single_transformation_is_accepted() {
+    use super::WordTransformations;
+
+    let mut json = Configuration::default().to_json_string().unwrap();
+    json = json.replace("[\n      \"AlternatingLowerUpper\"\n    ]", "\"UpperCase\"");
+    let config = Configuration::from_json_str(&json).unwrap();
+    assert_eq!(config.words.transformations, vec![WordTransformations::UpperCase]);
+}
@@ -0,0 +1,132 @@
+//! Validation of a configuration before it is used to generate a password.
+
+use std::error::Error;
+use std::fmt;
+
+use super::{Configuration, PaddingCharTypes};
+use super::super::word_list::WordList;
+
+/// The largest amount of padding digits that can be generated before or after the password.
+/// Any more digits would not fit in a `u64`.
+pub const MAX_PADDING_DIGITS: u8 = 19;
+
+/// The reasons a configuration cannot be used to generate a password
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    /// The minimum word length is larger than the maximum word length
+    InvalidWordLength {
+        /// The minimum word length in the configuration
+        min_length: u8,
+        /// The maximum word length in the configuration
+        max_length: u8,
+    },
+    /// The word list does not contain any words within the minimum and maximum word length
+    NoMatchingWords {
+        /// The minimum word length in the configuration
+        min_length: u8,
+        /// The maximum word length in the configuration
+        max_length: u8,
+    },
+    /// The `seperators` vector in `SeperatorConfiguration` is empty
+    NoSeperators,
+    /// The `padding_chars` vector in `PaddingSymbolConfiguration` is empty but the padding
+    /// character type needs to choose from it
+    NoPaddingCharacters,
+    /// More padding digits were requested than `MAX_PADDING_DIGITS`
+    TooManyPaddingDigits(u8),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match *self {
+            ConfigError::InvalidWordLength { min_length, max_length } => {
+                write!(f, "the minimum word length ({}) is larger than the maximum word length ({})", min_length, max_length)
+            },
+            ConfigError::NoMatchingWords { min_length, max_length } => {
+                write!(f, "the word list has no words between {} and {} characters long", min_length, max_length)
+            },
+            ConfigError::NoSeperators => {
+                write!(f, "no seperator characters were given")
+            },
+            ConfigError::NoPaddingCharacters => {
+                write!(f, "no padding characters were given")
+            },
+            ConfigError::TooManyPaddingDigits(num_digits) => {
+                write!(f, "{} padding digits were requested but at most {} are supported", num_digits, MAX_PADDING_DIGITS)
+            },
+        };
+    }
+}
+
+impl Error for ConfigError {}
+
+impl Configuration {
+    /// Checks that a password can be generated with this configuration and the given word list.
+    ///
+    /// ```
+    /// use xkcd_pass::{Configuration, SimpleEnglish};
+    /// use xkcd_pass::configuration::ConfigError;
+    ///
+    /// let word_list = SimpleEnglish::new();
+    /// assert_eq!(Configuration::default().validate(&word_list), Ok(()));
+    ///
+    /// let mut config = Configuration::default();
+    /// config.seperator.seperators.clear();
+    /// assert_eq!(config.validate(&word_list), Err(ConfigError::NoSeperators));
+    /// ```
+    pub fn validate<A: WordList>(&self, word_list: &A) -> Result<(), ConfigError> {
+        self.validate_settings()?;
+
+        let min_length = self.words.min_length;
+        let max_length = self.words.max_length;
+        let has_words = match word_list.get_words_slice(min_length, max_length) {
+            Some(words) => !words.is_empty(),
+            None => !word_list.get_words(min_length, max_length).is_empty(),
+        };
+        if self.words.num_words > 0 && !has_words {
+            return Err(ConfigError::NoMatchingWords { min_length, max_length });
+        }
+
+        return Ok(());
+    }
+
+    /// Checks the settings of the configuration which do not depend on a word list. Every
+    /// error except `ConfigError::NoMatchingWords` is found.
+    ///
+    /// ```
+    /// use xkcd_pass::Configuration;
+    /// use xkcd_pass::configuration::ConfigError;
+    ///
+    /// let mut config = Configuration::default();
+    /// config.padding_digits.num_after = 20;
+    /// assert_eq!(config.validate_settings(), Err(ConfigError::TooManyPaddingDigits(20)));
+    /// ```
+    pub fn validate_settings(&self) -> Result<(), ConfigError> {
+        let min_length = self.words.min_length;
+        let max_length = self.words.max_length;
+        if min_length > max_length {
+            return Err(ConfigError::InvalidWordLength { min_length, max_length });
+        }
+
+        if self.seperator.seperators.is_empty() {
+            return Err(ConfigError::NoSeperators);
+        }
+
+        match self.padding_symbols.padding_character_type {
+            PaddingCharTypes::SingleCharacter | PaddingCharTypes::RandomCharacter => {
+                if self.padding_symbols.padding_chars.is_empty() {
+                    return Err(ConfigError::NoPaddingCharacters);
+                }
+            },
+            PaddingCharTypes::SeperatorCharacter => {},
+        }
+
+        for &num_digits in &[self.padding_digits.num_before, self.padding_digits.num_after] {
+            if num_digits > MAX_PADDING_DIGITS {
+                return Err(ConfigError::TooManyPaddingDigits(num_digits));
+            }
+        }
+
+        return Ok(());
+    }
+}
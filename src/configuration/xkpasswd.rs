@@ -0,0 +1,376 @@
+//! Conversion between configurations and the JSON configurations exported by xkpasswd.net.
+//!
+//! The xkpasswd perl module describes a configuration with keys such as `num_words`,
+//! `case_transform` and `padding_type`. `XkpasswdConfig` mirrors that schema so that a
+//! configuration can be pasted from the xkpasswd.net web interface and used to generate
+//! passwords with the same policy. Options which this crate cannot generate are reported as an
+//! `XkpasswdError` rather than being silently approximated.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(feature = "json")]
+//! # fn main() {
+//! use xkcd_pass::Configuration;
+//!
+//! let config = Configuration::from_xkpasswd_json(r#"{
+//!     "num_words": 4,
+//!     "word_length_min": 4,
+//!     "word_length_max": 8,
+//!     "case_transform": "CAPITALISE",
+//!     "separator_character": "-",
+//!     "padding_digits_before": 0,
+//!     "padding_digits_after": 2,
+//!     "padding_type": "FIXED",
+//!     "padding_character": "RANDOM",
+//!     "symbol_alphabet": ["!", "?", "@"],
+//!     "padding_characters_before": 0,
+//!     "padding_characters_after": 1,
+//!     "random_increment": "AUTO"
+//! }"#).unwrap();
+//! assert_eq!(config.words.num_words, 4);
+//! # }
+//! # #[cfg(not(feature = "json"))]
+//! # fn main() {}
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+#[cfg(feature = "json")]
+use serde_json;
+
+use super::{Configuration, WordConfiguration, WordTransformations, SeperatorConfiguration, SeperatorTypes,
+            PaddingDigitConfiguration, PaddingSymbolConfiguration, PaddingTypes, PaddingCharTypes};
+
+/// The value of `separator_character` or `padding_character` which chooses a random character
+const RANDOM_CHARACTER: &str = "RANDOM";
+/// The value of `separator_character` which leaves out the seperator
+const NO_CHARACTER: &str = "NONE";
+/// The value of `padding_character` which uses the seperator character
+const SEPARATOR_CHARACTER: &str = "SEPARATOR";
+
+/// A configuration in the schema used by xkpasswd. Keys that this crate does not use, such as
+/// `random_increment` and `allow_accents`, are ignored.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct XkpasswdConfig {
+    /// The number of words in the password
+    pub num_words: u8,
+    /// The minimum length of the words
+    pub word_length_min: u8,
+    /// The maximum length of the words
+    pub word_length_max: u8,
+    /// How the case of the words is changed
+    pub case_transform: XkpasswdCaseTransform,
+    /// `NONE`, `RANDOM` or the seperator character
+    pub separator_character: String,
+    /// The characters a random seperator is chosen from. `symbol_alphabet` is used if missing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub separator_alphabet: Option<Vec<char>>,
+    /// The number of padding digits before the password
+    pub padding_digits_before: u8,
+    /// The number of padding digits after the password
+    pub padding_digits_after: u8,
+    /// How the password is padded with symbols
+    pub padding_type: XkpasswdPaddingType,
+    /// `RANDOM`, `SEPARATOR` or the padding character. Not needed when there is no padding
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub padding_character: Option<String>,
+    /// The characters a random padding character is chosen from. `symbol_alphabet` is used if
+    /// missing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub padding_alphabet: Option<Vec<char>>,
+    /// The characters random seperators and padding characters are chosen from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub symbol_alphabet: Option<Vec<char>>,
+    /// The number of padding characters before the password when the padding type is `FIXED`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub padding_characters_before: Option<u8>,
+    /// The number of padding characters after the password when the padding type is `FIXED`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub padding_characters_after: Option<u8>,
+    /// The length to pad the password to when the padding type is `ADAPTIVE`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pad_to_length: Option<u32>,
+}
+
+/// The values of the xkpasswd `case_transform` key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum XkpasswdCaseTransform {
+    /// Leave the words as they are in the dictionary. Not supported by this crate
+    None,
+    /// `UPPER CASE`
+    Upper,
+    /// `lower case`
+    Lower,
+    /// `Capitalise First`
+    Capitalise,
+    /// `iNVERT cAPITALISATION`
+    Invert,
+    /// `alternate CASE`
+    Alternate,
+    /// `RANDOM case`
+    Random,
+}
+
+/// The values of the xkpasswd `padding_type` key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum XkpasswdPaddingType {
+    /// No padding symbols
+    None,
+    /// A fixed amount of padding symbols before and after the password
+    Fixed,
+    /// Pad the password to a fixed length, or truncate it if it is longer. This crate never
+    /// truncates, so it is only supported when no password is longer than the length
+    Adaptive,
+}
+
+/// The reasons a configuration could not be converted to or from the xkpasswd schema
+#[derive(Debug)]
+pub enum XkpasswdError {
+    /// The setting has a value that this crate cannot generate passwords for
+    Unsupported {
+        /// The name of the xkpasswd key
+        setting: &'static str,
+        /// The unsupported value
+        value: String,
+    },
+    /// A key which is needed by the other settings is missing
+    MissingSetting(&'static str),
+    /// A character setting is not `RANDOM`, `NONE`, `SEPARATOR` or a single character
+    InvalidCharacter {
+        /// The name of the xkpasswd key
+        setting: &'static str,
+        /// The invalid value
+        value: String,
+    },
+    /// The JSON could not be parsed or written
+    #[cfg(feature = "json")]
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for XkpasswdError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match *self {
+            XkpasswdError::Unsupported { setting, ref value } => write!(f, "unsupported value '{}' for '{}'", value, setting),
+            XkpasswdError::MissingSetting(setting) => write!(f, "missing setting '{}'", setting),
+            XkpasswdError::InvalidCharacter { setting, ref value } => write!(f, "invalid character '{}' for '{}'", value, setting),
+            #[cfg(feature = "json")]
+            XkpasswdError::Json(ref err) => write!(f, "invalid xkpasswd JSON: {}", err),
+        };
+    }
+}
+
+impl Error for XkpasswdError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        return match *self {
+            #[cfg(feature = "json")]
+            XkpasswdError::Json(ref err) => Some(err),
+            _ => None,
+        };
+    }
+}
+
+impl Configuration {
+    /// Converts an xkpasswd configuration into a configuration for this crate.
+    pub fn from_xkpasswd(xkpasswd: &XkpasswdConfig) -> Result<Configuration, XkpasswdError> {
+        let transformation = match xkpasswd.case_transform {
+            XkpasswdCaseTransform::None => return Err(unsupported("case_transform", "NONE")),
+            XkpasswdCaseTransform::Upper => WordTransformations::UpperCase,
+            XkpasswdCaseTransform::Lower => WordTransformations::LowerCase,
+            XkpasswdCaseTransform::Capitalise => WordTransformations::CapitaliseFirst,
+            XkpasswdCaseTransform::Invert => WordTransformations::CapitaliseNonFirst,
+            XkpasswdCaseTransform::Alternate => WordTransformations::AlternatingLowerUpper,
+            XkpasswdCaseTransform::Random => WordTransformations::RandomLowerUpper,
+        };
+        let transformations = vec![transformation];
+
+        let seperator = match xkpasswd.separator_character.as_str() {
+            NO_CHARACTER => return Err(unsupported("separator_character", NO_CHARACTER)),
+            RANDOM_CHARACTER => {
+                let alphabet = xkpasswd.separator_alphabet.as_ref().or(xkpasswd.symbol_alphabet.as_ref())
+                                       .ok_or(XkpasswdError::MissingSetting("separator_alphabet"))?;
+                SeperatorConfiguration {
+                    seperator_type: SeperatorTypes::RandomCharacter,
+                    seperators: alphabet.clone(),
+                }
+            },
+            character => SeperatorConfiguration {
+                seperator_type: SeperatorTypes::SingleCharacter,
+                seperators: vec![single_character("separator_character", character)?],
+            },
+        };
+
+        let padding_type = match xkpasswd.padding_type {
+            XkpasswdPaddingType::None => PaddingTypes::Fixed(0, 0),
+            XkpasswdPaddingType::Fixed => {
+                let before = xkpasswd.padding_characters_before.ok_or(XkpasswdError::MissingSetting("padding_characters_before"))?;
+                let after = xkpasswd.padding_characters_after.ok_or(XkpasswdError::MissingSetting("padding_characters_after"))?;
+                PaddingTypes::Fixed(before, after)
+            },
+            XkpasswdPaddingType::Adaptive => {
+                let pad_to_length = xkpasswd.pad_to_length.ok_or(XkpasswdError::MissingSetting("pad_to_length"))?;
+                check_no_truncation(pad_to_length, xkpasswd.num_words, xkpasswd.word_length_max,
+                                    xkpasswd.padding_digits_before, xkpasswd.padding_digits_after)?;
+                PaddingTypes::Adaptive(pad_to_length)
+            },
+        };
+
+        let padding_alphabet = xkpasswd.padding_alphabet.as_ref().or(xkpasswd.symbol_alphabet.as_ref());
+        let (padding_character_type, padding_chars) = match xkpasswd.padding_character.as_deref() {
+            Some(RANDOM_CHARACTER) => {
+                let alphabet = padding_alphabet.ok_or(XkpasswdError::MissingSetting("padding_alphabet"))?;
+                (PaddingCharTypes::RandomCharacter, alphabet.clone())
+            },
+            Some(SEPARATOR_CHARACTER) => (PaddingCharTypes::SeperatorCharacter, vec![]),
+            Some(character) => (PaddingCharTypes::SingleCharacter, vec![single_character("padding_character", character)?]),
+            // The padding character is never used
+            None if xkpasswd.padding_type == XkpasswdPaddingType::None => (PaddingCharTypes::SeperatorCharacter, vec![]),
+            None => return Err(XkpasswdError::MissingSetting("padding_character")),
+        };
+
+        return Ok(Configuration {
+            words: WordConfiguration {
+                num_words: xkpasswd.num_words,
+                min_length: xkpasswd.word_length_min,
+                max_length: xkpasswd.word_length_max,
+
+                transformations,
+            },
+            seperator,
+            padding_digits: PaddingDigitConfiguration {
+                num_before: xkpasswd.padding_digits_before,
+                num_after: xkpasswd.padding_digits_after,
+            },
+            padding_symbols: PaddingSymbolConfiguration {
+                padding_type,
+                padding_character_type,
+                padding_chars,
+            },
+        });
+    }
+
+    /// Converts the configuration into the xkpasswd schema.
+    ///
+    /// ```
+    /// use xkcd_pass::Configuration;
+    /// use xkcd_pass::configuration::XkpasswdCaseTransform;
+    ///
+    /// let xkpasswd = Configuration::xkcd().to_xkpasswd().unwrap();
+    /// assert_eq!(xkpasswd.case_transform, XkpasswdCaseTransform::Random);
+    /// assert_eq!(Configuration::from_xkpasswd(&xkpasswd).unwrap(), Configuration::xkcd());
+    /// ```
+    pub fn to_xkpasswd(&self) -> Result<XkpasswdConfig, XkpasswdError> {
+        let case_transform = match self.words.transformations.as_slice() {
+            // The words are always lower case if they are not transformed
+            [] => XkpasswdCaseTransform::Lower,
+            [ref transformation] => xkpasswd_case_transform(transformation)?,
+            _ => return Err(unsupported("case_transform", "multiple transformations")),
+        };
+
+        let (separator_character, separator_alphabet) = match self.seperator.seperator_type {
+            SeperatorTypes::SingleCharacter => {
+                let character = self.seperator.seperators.first().ok_or(XkpasswdError::MissingSetting("separator_character"))?;
+                (character.to_string(), None)
+            },
+            SeperatorTypes::RandomCharacter => (RANDOM_CHARACTER.to_string(), Some(self.seperator.seperators.clone())),
+            SeperatorTypes::RandomCharacterPerGap => return Err(unsupported("separator_character", "RandomCharacterPerGap")),
+        };
+
+        let (padding_type, padding_characters_before, padding_characters_after, pad_to_length) = match self.padding_symbols.padding_type {
+            PaddingTypes::Fixed(0, 0) => (XkpasswdPaddingType::None, None, None, None),
+            PaddingTypes::Fixed(before, after) => (XkpasswdPaddingType::Fixed, Some(before), Some(after), None),
+            PaddingTypes::Adaptive(length) => {
+                check_no_truncation(length, self.words.num_words, self.words.max_length,
+                                    self.padding_digits.num_before, self.padding_digits.num_after)?;
+                (XkpasswdPaddingType::Adaptive, None, None, Some(length))
+            },
+        };
+
+        let (padding_character, padding_alphabet) = match self.padding_symbols.padding_character_type {
+            PaddingCharTypes::SingleCharacter => match self.padding_symbols.padding_chars.first() {
+                Some(character) => (Some(character.to_string()), None),
+                None if padding_type == XkpasswdPaddingType::None => (None, None),
+                None => return Err(XkpasswdError::MissingSetting("padding_character")),
+            },
+            PaddingCharTypes::RandomCharacter => (Some(RANDOM_CHARACTER.to_string()), Some(self.padding_symbols.padding_chars.clone())),
+            PaddingCharTypes::SeperatorCharacter => (Some(SEPARATOR_CHARACTER.to_string()), None),
+        };
+
+        return Ok(XkpasswdConfig {
+            num_words: self.words.num_words,
+            word_length_min: self.words.min_length,
+            word_length_max: self.words.max_length,
+            case_transform,
+            separator_character,
+            separator_alphabet,
+            padding_digits_before: self.padding_digits.num_before,
+            padding_digits_after: self.padding_digits.num_after,
+            padding_type,
+            padding_character,
+            padding_alphabet,
+            symbol_alphabet: None,
+            padding_characters_before,
+            padding_characters_after,
+            pad_to_length,
+        });
+    }
+
+    /// Reads a configuration from the JSON exported by xkpasswd.net.
+    #[cfg(feature = "json")]
+    pub fn from_xkpasswd_json(s: &str) -> Result<Configuration, XkpasswdError> {
+        let xkpasswd: XkpasswdConfig = serde_json::from_str(s).map_err(XkpasswdError::Json)?;
+        return Configuration::from_xkpasswd(&xkpasswd);
+    }
+
+    /// Writes the configuration as JSON which can be imported into xkpasswd.net.
+    #[cfg(feature = "json")]
+    pub fn to_xkpasswd_json(&self) -> Result<String, XkpasswdError> {
+        let xkpasswd = self.to_xkpasswd()?;
+        return serde_json::to_string_pretty(&xkpasswd).map_err(XkpasswdError::Json);
+    }
+}
+
+/// Finds the xkpasswd case transformation which matches a word transformation
+fn xkpasswd_case_transform(transformation: &WordTransformations) -> Result<XkpasswdCaseTransform, XkpasswdError> {
+    return match *transformation {
+        WordTransformations::CapitaliseFirst => Ok(XkpasswdCaseTransform::Capitalise),
+        WordTransformations::CapitaliseNonFirst => Ok(XkpasswdCaseTransform::Invert),
+        WordTransformations::LowerCase => Ok(XkpasswdCaseTransform::Lower),
+        WordTransformations::UpperCase => Ok(XkpasswdCaseTransform::Upper),
+        WordTransformations::AlternatingLowerUpper => Ok(XkpasswdCaseTransform::Alternate),
+        WordTransformations::RandomLowerUpper => Ok(XkpasswdCaseTransform::Random),
+        WordTransformations::RandomCharacterCase => Err(unsupported("case_transform", "RandomCharacterCase")),
+        WordTransformations::Leetspeak => Err(unsupported("case_transform", "Leetspeak")),
+        WordTransformations::RandomLeetspeak => Err(unsupported("case_transform", "RandomLeetspeak")),
+        WordTransformations::Reverse => Err(unsupported("case_transform", "Reverse")),
+    };
+}
+
+/// Checks that adaptive padding never has to truncate the password. xkpasswd truncates passwords
+/// which are longer than the padding length but this crate only pads them, so the two would
+/// generate different passwords
+fn check_no_truncation(pad_to_length: u32, num_words: u8, word_length_max: u8, digits_before: u8, digits_after: u8) -> Result<(), XkpasswdError> {
+    // The longest words, a seperator between each word and the digits, and the digits
+    let num_seperators = (num_words as u32).saturating_sub(1) + (digits_before > 0) as u32 + (digits_after > 0) as u32;
+    let max_length = num_words as u32 * word_length_max as u32 + num_seperators + digits_before as u32 + digits_after as u32;
+    if max_length > pad_to_length {
+        return Err(unsupported("pad_to_length", &format!("{} (passwords of up to {} characters would be truncated)", pad_to_length, max_length)));
+    }
+    return Ok(());
+}
+
+fn unsupported(setting: &'static str, value: &str) -> XkpasswdError {
+    return XkpasswdError::Unsupported { setting, value: value.to_string() };
+}
+
+/// Parses a setting which must contain exactly one character
+fn single_character(setting: &'static str, value: &str) -> Result<char, XkpasswdError> {
+    let mut chars = value.chars();
+    return match (chars.next(), chars.next()) {
+        (Some(character), None) => Ok(character),
+        _ => Err(XkpasswdError::InvalidCharacter { setting, value: value.to_string() }),
+    };
+}
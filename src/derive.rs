@@ -0,0 +1,195 @@
+//! Derives passwords from a master secret and a site name, so the same password can be generated
+//! again without storing it.
+//!
+//! The master secret, site name and counter are run through a key derivation function, and the
+//! result seeds the random number generator used to generate the password. Increase the counter
+//! to get a new password for the same site.
+//!
+//! Each key derivation function is behind a cargo feature of the same name: `pbkdf2`, `scrypt`
+//! and `argon2`.
+//!
+//! The derived password also depends on the configuration and the word list, so both must be
+//! kept the same to get the same password again.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(feature = "pbkdf2")]
+//! # fn main() {
+//! use xkcd_pass::{Configuration, SimpleEnglish};
+//! use xkcd_pass::derive::{derive_password, Kdf};
+//!
+//! let config = Configuration::default();
+//! let word_list = SimpleEnglish::new();
+//! let kdf = Kdf::Pbkdf2 { iterations: 1000 };
+//!
+//! let first = derive_password(&config, &word_list, "master secret", "example.com", 1, &kdf).unwrap();
+//! let second = derive_password(&config, &word_list, "master secret", "example.com", 1, &kdf).unwrap();
+//! assert_eq!(first, second);
+//! # }
+//! # #[cfg(not(feature = "pbkdf2"))]
+//! # fn main() {}
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use rand::{ChaChaRng, SeedableRng};
+
+#[cfg(feature = "pbkdf2")]
+use pbkdf2;
+#[cfg(feature = "pbkdf2")]
+use sha2::Sha256;
+#[cfg(feature = "scrypt")]
+use scrypt;
+#[cfg(feature = "argon2")]
+use argon2::{self, Argon2};
+
+use super::configuration::{Configuration, ConfigError};
+use super::generator::{generate_password_with_rng, wipe};
+use super::word_list::WordList;
+
+#[cfg(test)]
+mod tests;
+
+/// Separates the salt from salts used by other programs. Changing it changes every derived
+/// password
+const SALT_PREFIX: &[u8] = b"xkcd_pass derive v1";
+
+/// The key derivation functions and their parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kdf {
+    /// PBKDF2 with HMAC-SHA256, requires the `pbkdf2` feature
+    #[cfg(feature = "pbkdf2")]
+    Pbkdf2 {
+        /// The number of iterations
+        iterations: u32,
+    },
+    /// scrypt, requires the `scrypt` feature
+    #[cfg(feature = "scrypt")]
+    Scrypt {
+        /// The base 2 logarithm of the CPU and memory cost
+        log_n: u8,
+        /// The block size
+        r: u32,
+        /// The parallelism
+        p: u32,
+    },
+    /// Argon2id, requires the `argon2` feature
+    #[cfg(feature = "argon2")]
+    Argon2 {
+        /// The memory cost in KiB
+        memory_kib: u32,
+        /// The number of iterations
+        iterations: u32,
+        /// The parallelism
+        parallelism: u32,
+    },
+}
+
+impl Kdf {
+    /// PBKDF2 with 600,000 iterations, as recommended by OWASP for HMAC-SHA256
+    #[cfg(feature = "pbkdf2")]
+    pub fn default_pbkdf2() -> Kdf {
+        return Kdf::Pbkdf2 { iterations: 600_000 };
+    }
+
+    /// scrypt with a cost of 2^17, a block size of 8 and a parallelism of 1, as recommended by
+    /// OWASP
+    #[cfg(feature = "scrypt")]
+    pub fn default_scrypt() -> Kdf {
+        return Kdf::Scrypt { log_n: 17, r: 8, p: 1 };
+    }
+
+    /// Argon2id with 19 MiB of memory, 2 iterations and a parallelism of 1, as recommended by
+    /// OWASP
+    #[cfg(feature = "argon2")]
+    pub fn default_argon2() -> Kdf {
+        return Kdf::Argon2 { memory_kib: 19 * 1024, iterations: 2, parallelism: 1 };
+    }
+}
+
+/// The reasons a password could not be derived
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeriveError {
+    /// The configuration cannot be used with the word list
+    InvalidConfiguration(ConfigError),
+    /// The parameters of the key derivation function are invalid
+    InvalidKdfParameters(String),
+}
+
+impl fmt::Display for DeriveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match *self {
+            DeriveError::InvalidConfiguration(ref err) => write!(f, "invalid configuration: {}", err),
+            DeriveError::InvalidKdfParameters(ref err) => write!(f, "invalid key derivation parameters: {}", err),
+        };
+    }
+}
+
+impl Error for DeriveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        return match *self {
+            DeriveError::InvalidConfiguration(ref err) => Some(err),
+            DeriveError::InvalidKdfParameters(_) => None,
+        };
+    }
+}
+
+impl From<ConfigError> for DeriveError {
+    fn from(err: ConfigError) -> DeriveError {
+        return DeriveError::InvalidConfiguration(err);
+    }
+}
+
+/// Derives a password from the master secret, site name and counter. The same inputs, with the
+/// same configuration and word list, always derive the same password.
+pub fn derive_password<A: WordList>(config: &Configuration, word_list: &A, master_secret: &str, site: &str, counter: u32, kdf: &Kdf) -> Result<String, DeriveError> {
+    config.validate(word_list)?;
+
+    let mut key = derive_key(master_secret, site, counter, kdf)?;
+    let mut seed: Vec<u32> = key.chunks(4).map(|a| u32::from_le_bytes([a[0], a[1], a[2], a[3]])).collect();
+    let mut rgen = ChaChaRng::from_seed(&seed[..]);
+    wipe(&mut key);
+    wipe(&mut seed);
+
+    return Ok(generate_password_with_rng(config, word_list, &mut rgen));
+}
+
+/// Runs the key derivation function, giving 32 bytes to seed the random number generator
+fn derive_key(master_secret: &str, site: &str, counter: u32, kdf: &Kdf) -> Result<[u8; 32], DeriveError> {
+    let salt = salt(site, counter);
+    let mut key = [0u8; 32];
+
+    match *kdf {
+        #[cfg(feature = "pbkdf2")]
+        Kdf::Pbkdf2 { iterations } => {
+            if iterations == 0 {
+                return Err(DeriveError::InvalidKdfParameters("PBKDF2 needs at least 1 iteration".to_string()));
+            }
+            pbkdf2::pbkdf2_hmac::<Sha256>(master_secret.as_bytes(), &salt, iterations, &mut key);
+        },
+        #[cfg(feature = "scrypt")]
+        Kdf::Scrypt { log_n, r, p } => {
+            let params = scrypt::Params::new(log_n, r, p, key.len()).map_err(|err| DeriveError::InvalidKdfParameters(err.to_string()))?;
+            scrypt::scrypt(master_secret.as_bytes(), &salt, &params, &mut key).map_err(|err| DeriveError::InvalidKdfParameters(err.to_string()))?;
+        },
+        #[cfg(feature = "argon2")]
+        Kdf::Argon2 { memory_kib, iterations, parallelism } => {
+            let params = argon2::Params::new(memory_kib, iterations, parallelism, Some(key.len())).map_err(|err| DeriveError::InvalidKdfParameters(err.to_string()))?;
+            let argon2 = Argon2::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, params);
+            argon2.hash_password_into(master_secret.as_bytes(), &salt, &mut key).map_err(|err| DeriveError::InvalidKdfParameters(err.to_string()))?;
+        },
+    }
+
+    return Ok(key);
+}
+
+/// Builds the salt from the site name and counter. The length of the site name is included so
+/// different site names and counters can never give the same salt
+fn salt(site: &str, counter: u32) -> Vec<u8> {
+    let mut salt = SALT_PREFIX.to_vec();
+    salt.extend_from_slice(&(site.len() as u32).to_be_bytes());
+    salt.extend_from_slice(site.as_bytes());
+    salt.extend_from_slice(&counter.to_be_bytes());
+    return salt;
+}
@@ -0,0 +1,65 @@
+use super::{derive_key, derive_password, Kdf};
+use super::super::configuration::Configuration;
+use super::super::word_list::SimpleEnglish;
+
+/// A cheap key derivation function from whichever feature is enabled
+fn cheap_kdf() -> Kdf {
+    #[cfg(feature = "pbkdf2")]
+    return Kdf::Pbkdf2 { iterations: 100 };
+    #[cfg(all(feature = "scrypt", not(feature = "pbkdf2")))]
+    return Kdf::Scrypt { log_n: 4, r: 8, p: 1 };
+    #[cfg(all(feature = "argon2", not(feature = "pbkdf2"), not(feature = "scrypt")))]
+    return Kdf::Argon2 { memory_kib: 64, iterations: 1, parallelism: 1 };
+}
+
+/// Formats the bytes as lower case hexadecimal
+#[cfg(any(feature = "pbkdf2", feature = "scrypt"))]
+fn to_hex(bytes: &[u8]) -> String {
+    return bytes.iter().map(|a| format!("{:02x}", a)).collect();
+}
+
+#[test]
+#[cfg(feature = "pbkdf2")]
+pub fn test_pbkdf2_key() {
+    // Checked against Python's hashlib.pbkdf2_hmac
+    let key = derive_key("correct horse", "example.com", 1, &Kdf::Pbkdf2 { iterations: 1000 }).unwrap();
+    assert_eq!(to_hex(&key), "dd787c033670a29127fa28c455393affe906157b980e73a8d4afba809ff3c78e");
+}
+
+#[test]
+#[cfg(feature = "scrypt")]
+pub fn test_scrypt_key() {
+    // Checked against Python's hashlib.scrypt
+    let key = derive_key("correct horse", "example.com", 1, &Kdf::Scrypt { log_n: 10, r: 8, p: 1 }).unwrap();
+    assert_eq!(to_hex(&key), "83212a8fb8bd17936bcff540f4f1cf416b1d250a51174ade62d4aa7f76aa783a");
+}
+
+#[test]
+#[cfg(feature = "argon2")]
+pub fn test_argon2_parameters() {
+    let kdf = Kdf::Argon2 { memory_kib: 0, iterations: 1, parallelism: 1 };
+    assert!(derive_key("correct horse", "example.com", 1, &kdf).is_err());
+}
+
+#[test]
+pub fn test_derived_passwords() {
+    let config = Configuration::default();
+    let word_list = SimpleEnglish::new();
+    let kdf = cheap_kdf();
+
+    let password = derive_password(&config, &word_list, "correct horse", "example.com", 1, &kdf).unwrap();
+    assert_eq!(password, derive_password(&config, &word_list, "correct horse", "example.com", 1, &kdf).unwrap());
+    assert!(password != derive_password(&config, &word_list, "correct horse", "example.com", 2, &kdf).unwrap());
+    assert!(password != derive_password(&config, &word_list, "correct horse", "example.org", 1, &kdf).unwrap());
+    assert!(password != derive_password(&config, &word_list, "battery staple", "example.com", 1, &kdf).unwrap());
+}
+
+#[test]
+#[cfg(feature = "pbkdf2")]
+pub fn test_derived_password_known_answer() {
+    // Changing the generator, the default configuration or the word list changes every derived
+    // password, which would lock users out of their sites
+    let kdf = Kdf::Pbkdf2 { iterations: 1000 };
+    let password = derive_password(&Configuration::default(), &SimpleEnglish::new(), "correct horse", "example.com", 1, &kdf).unwrap();
+    assert_eq!(password, "!!39+study+CLOSE+oxygen+75!!");
+}
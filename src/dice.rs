@@ -0,0 +1,170 @@
+//! Generates passwords from physical dice rolls instead of a random number generator.
+//!
+//! Each word is chosen by rolling dice and looking the rolls up in a `DicewareWordList`. The rest
+//! of the configuration is then applied as normal, so it must not need any random choices: the
+//! seperator and padding characters must be fixed, there can be no padding digits and no random
+//! word transformations. The minimum and maximum word lengths are ignored, as every word in the
+//! list can be rolled. `dice_configuration` removes the random choices from a configuration, such
+//! as one of the presets.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(feature = "eff-large")]
+//! # fn main() {
+//! use xkcd_pass::{generate_password_from_dice, Configuration};
+//! use xkcd_pass::configuration::WordTransformations;
+//! use xkcd_pass::word_list::EffLarge;
+//!
+//! // The xkcd preset randomly capitalises words, so capitalise the first letter instead
+//! let mut config = Configuration::xkcd();
+//! config.words.transformations = vec![WordTransformations::CapitaliseFirst];
+//!
+//! let password = generate_password_from_dice(&config, &EffLarge::new(), "11111 11112 66665 66666").unwrap();
+//! assert_eq!(password, "Abacus-Abdomen-Zoology-Zoom");
+//! # }
+//! # #[cfg(not(feature = "eff-large"))]
+//! # fn main() {}
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use rand::Rng;
+
+use super::configuration::{Configuration, ConfigError, WordTransformations, SeperatorTypes, PaddingCharTypes};
+use super::generator::assemble_password;
+use super::word_list::DicewareWordList;
+
+#[cfg(test)]
+mod tests;
+
+/// The reasons a password could not be generated from dice rolls
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiceError {
+    /// The configuration cannot be used with the word list
+    InvalidConfiguration(ConfigError),
+    /// The configuration needs a random choice which cannot be made with the dice rolls. Contains
+    /// the name of the setting
+    NeedsRandomness(&'static str),
+    /// A different number of rolls were given than the number of words in the configuration
+    WrongNumberOfRolls {
+        /// The number of words in the configuration
+        expected: usize,
+        /// The number of rolls given
+        found: usize,
+    },
+    /// A roll has the wrong number of dice or contains something other than the numbers 1 to 6
+    InvalidRoll(String),
+}
+
+impl fmt::Display for DiceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match *self {
+            DiceError::InvalidConfiguration(ref err) => write!(f, "invalid configuration: {}", err),
+            DiceError::NeedsRandomness(setting) => write!(f, "the {} setting needs a random number generator", setting),
+            DiceError::WrongNumberOfRolls { expected, found } => write!(f, "expected {} rolls but {} were given", expected, found),
+            DiceError::InvalidRoll(ref roll) => write!(f, "invalid roll '{}'", roll),
+        };
+    }
+}
+
+impl Error for DiceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        return match *self {
+            DiceError::InvalidConfiguration(ref err) => Some(err),
+            _ => None,
+        };
+    }
+}
+
+impl From<ConfigError> for DiceError {
+    fn from(err: ConfigError) -> DiceError {
+        return DiceError::InvalidConfiguration(err);
+    }
+}
+
+/// Generates a password from whitespace separated dice rolls, one roll for each word, e.g.
+/// `"41523 21334 65112 13425"`. No random number generator is used.
+pub fn generate_password_from_dice<A: DicewareWordList>(config: &Configuration, word_list: &A, rolls: &str) -> Result<String, DiceError> {
+    check_dice_configuration(config, word_list)?;
+
+    let rolls: Vec<&str> = rolls.split_whitespace().collect();
+    let num_words = config.words.num_words as usize;
+    if rolls.len() != num_words {
+        return Err(DiceError::WrongNumberOfRolls { expected: num_words, found: rolls.len() });
+    }
+
+    let mut chosen_words = Vec::with_capacity(num_words);
+    for roll in rolls {
+        match word_list.get_word_for_rolls(roll) {
+            Some(word) => chosen_words.push(word.clone()),
+            None => return Err(DiceError::InvalidRoll(roll.to_string())),
+        }
+    }
+
+    return Ok(assemble_password(config, chosen_words, &mut NoRandomness));
+}
+
+/// Removes the random choices from the configuration so it can be used with dice rolls. The random
+/// word transformations are removed, the first seperator and padding character are always used
+/// and there are no padding digits.
+///
+/// ```
+/// use xkcd_pass::Configuration;
+/// use xkcd_pass::configuration::SeperatorTypes;
+/// use xkcd_pass::dice::dice_configuration;
+///
+/// let config = dice_configuration(&Configuration::default());
+/// assert_eq!(config.seperator.seperator_type, SeperatorTypes::SingleCharacter);
+/// assert_eq!(config.padding_digits.num_before, 0);
+/// ```
+pub fn dice_configuration(config: &Configuration) -> Configuration {
+    let mut config = config.clone();
+    config.words.transformations.retain(|a| !is_random_transformation(a));
+    config.seperator.seperator_type = SeperatorTypes::SingleCharacter;
+    if config.padding_symbols.padding_character_type == PaddingCharTypes::RandomCharacter {
+        config.padding_symbols.padding_character_type = PaddingCharTypes::SingleCharacter;
+    }
+    config.padding_digits.num_before = 0;
+    config.padding_digits.num_after = 0;
+    return config;
+}
+
+/// Checks that the configuration is valid and that it does not need any random choices
+fn check_dice_configuration<A: DicewareWordList>(config: &Configuration, word_list: &A) -> Result<(), DiceError> {
+    // Every word can be rolled, so the word lengths are not checked
+    let mut unrestricted = config.clone();
+    unrestricted.words.min_length = 0;
+    unrestricted.words.max_length = u8::MAX;
+    unrestricted.validate(word_list)?;
+
+    if config.words.transformations.iter().any(is_random_transformation) {
+        return Err(DiceError::NeedsRandomness("transformations"));
+    }
+    if config.seperator.seperator_type != SeperatorTypes::SingleCharacter {
+        return Err(DiceError::NeedsRandomness("seperator_type"));
+    }
+    if config.padding_symbols.padding_character_type == PaddingCharTypes::RandomCharacter {
+        return Err(DiceError::NeedsRandomness("padding_character_type"));
+    }
+    if config.padding_digits.num_before > 0 || config.padding_digits.num_after > 0 {
+        return Err(DiceError::NeedsRandomness("padding_digits"));
+    }
+
+    return Ok(());
+}
+
+/// Whether the word transformation makes a random choice
+fn is_random_transformation(transformation: &WordTransformations) -> bool {
+    return matches!(*transformation, WordTransformations::RandomLowerUpper | WordTransformations::RandomCharacterCase | WordTransformations::RandomLeetspeak);
+}
+
+/// A random number generator which is never used. The configuration has already been checked to
+/// not need any random choices
+struct NoRandomness;
+
+impl Rng for NoRandomness {
+    fn next_u32(&mut self) -> u32 {
+        unreachable!("a random choice was made while generating a password from dice rolls");
+    }
+}
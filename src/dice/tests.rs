@@ -0,0 +1,94 @@
+use super::{dice_configuration, generate_password_from_dice, DiceError};
+use super::super::configuration::{Configuration, Preset, WordTransformations, SeperatorTypes};
+use super::super::word_list::{WordList, DicewareWordList};
+
+/// A word list chosen with a pair of dice
+struct TwoDice(Vec<String>);
+
+impl TwoDice {
+    fn new() -> TwoDice {
+        return TwoDice((0..36).map(|a| format!("word{}", a)).collect());
+    }
+}
+
+impl WordList for TwoDice {
+    fn get_words(&self, min_len: u8, max_len: u8) -> Vec<&String> {
+        let TwoDice(ref words) = *self;
+        return words.iter().filter(|&a| a.len() >= min_len as usize && a.len() <= max_len as usize).collect();
+    }
+}
+
+impl DicewareWordList for TwoDice {
+    fn num_dice(&self) -> usize {
+        return 2;
+    }
+
+    fn diceware_words(&self) -> &[String] {
+        let TwoDice(ref words) = *self;
+        return words;
+    }
+}
+
+#[test]
+pub fn test_rolls_choose_words() {
+    let word_list = TwoDice::new();
+    assert_eq!(word_list.get_word_for_rolls("11").unwrap(), "word0");
+    assert_eq!(word_list.get_word_for_rolls("21").unwrap(), "word6");
+    assert_eq!(word_list.get_word_for_rolls("66").unwrap(), "word35");
+    assert_eq!(word_list.get_word_for_rolls("70"), None);
+    assert_eq!(word_list.get_word_for_rolls("111"), None);
+
+    let mut config = Configuration::xkcd();
+    config.words.transformations = vec![WordTransformations::UpperCase];
+    let password = generate_password_from_dice(&config, &word_list, " 11 12\t65\n66 ").unwrap();
+    assert_eq!(password, "WORD0-WORD1-WORD34-WORD35");
+}
+
+#[test]
+pub fn test_dice_errors() {
+    let word_list = TwoDice::new();
+
+    // The xkcd preset randomly capitalises the words
+    let mut config = Configuration::xkcd();
+    assert_eq!(generate_password_from_dice(&config, &word_list, "11 12 13 14"), Err(DiceError::NeedsRandomness("transformations")));
+
+    config.words.transformations.clear();
+    config.seperator.seperator_type = SeperatorTypes::RandomCharacter;
+    assert_eq!(generate_password_from_dice(&config, &word_list, "11 12 13 14"), Err(DiceError::NeedsRandomness("seperator_type")));
+
+    config.seperator.seperator_type = SeperatorTypes::SingleCharacter;
+    config.padding_digits.num_after = 2;
+    assert_eq!(generate_password_from_dice(&config, &word_list, "11 12 13 14"), Err(DiceError::NeedsRandomness("padding_digits")));
+
+    config.padding_digits.num_after = 0;
+    assert_eq!(generate_password_from_dice(&config, &word_list, "11 12 13"), Err(DiceError::WrongNumberOfRolls { expected: 4, found: 3 }));
+    assert_eq!(generate_password_from_dice(&config, &word_list, "11 12 13 17"), Err(DiceError::InvalidRoll("17".to_string())));
+    assert!(generate_password_from_dice(&config, &word_list, "11 12 13 14").is_ok());
+}
+
+#[test]
+pub fn test_dice_configuration_of_presets() {
+    let word_list = TwoDice::new();
+    for preset in Preset::ALL {
+        let config = dice_configuration(&preset.configuration());
+        let rolls = vec!["11"; config.words.num_words as usize].join(" ");
+        assert!(generate_password_from_dice(&config, &word_list, &rolls).is_ok(), "{}", preset);
+    }
+
+    // The settings which are already fixed are kept
+    let mut config = Configuration::xkcd();
+    config.words.transformations = vec![WordTransformations::RandomLowerUpper, WordTransformations::UpperCase];
+    let config = dice_configuration(&config);
+    assert_eq!(config.words.transformations, vec![WordTransformations::UpperCase]);
+    assert_eq!(generate_password_from_dice(&config, &word_list, "11 12 13 14").unwrap(), "WORD0-WORD1-WORD2-WORD3");
+}
+
+#[cfg(feature = "eff-short")]
+#[test]
+pub fn test_eff_short_rolls() {
+    use super::super::word_list::EffShort;
+
+    let word_list = EffShort::new();
+    assert_eq!(word_list.get_word_for_rolls("1111").unwrap(), "acid");
+    assert_eq!(word_list.get_word_for_rolls("6666").unwrap(), "zoom");
+}
@@ -0,0 +1,312 @@
+//! Entropy statistics for a configuration and word list, in the same spirit as the statistics
+//! reported by xkpasswd.
+//!
+//! Two kinds of entropy are calculated:
+//!
+//! * The *blind* entropy assumes that the attacker knows nothing about how the password was
+//!   generated and would have to brute force every combination of the character classes that
+//!   appear in the password. As the length of the password depends on the words chosen, a
+//!   minimum and maximum blind entropy are given.
+//! * The *seen* entropy assumes that the attacker knows the configuration and the word list
+//!   that were used to generate the password.
+//!
+//! # Example
+//!
+//! ```
+//! use xkcd_pass::{calculate_entropy, Configuration, SimpleEnglish};
+//!
+//! let word_list = SimpleEnglish::new();
+//! let config = Configuration::default();
+//! let entropy = calculate_entropy(&config, &word_list).unwrap();
+//!
+//! println!("Blind entropy: between {:.0} and {:.0} bits", entropy.blind_min, entropy.blind_max);
+//! println!("Seen entropy: {:.0} bits", entropy.seen);
+//! ```
+
+use super::configuration::{Configuration, ConfigError, WordTransformations, SeperatorTypes, PaddingCharTypes, PaddingTypes};
+use super::generator::leetspeak_substitute;
+use super::word_list::WordList;
+
+#[cfg(test)]
+mod tests;
+
+/// The number of lowercase letters an attacker would have to try
+const LOWERCASE_ALPHABET: u32 = 26;
+/// The number of uppercase letters an attacker would have to try
+const UPPERCASE_ALPHABET: u32 = 26;
+/// The number of digits an attacker would have to try
+const DIGIT_ALPHABET: u32 = 10;
+/// The number of printable ASCII symbols an attacker would have to try
+const SYMBOL_ALPHABET: u32 = 33;
+
+/// The entropy statistics for a configuration and word list. All entropies are given in bits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entropy {
+    /// The blind entropy of the shortest password that the configuration can generate
+    pub blind_min: f64,
+    /// The blind entropy of the longest password that the configuration can generate
+    pub blind_max: f64,
+    /// The entropy when the attacker knows the configuration and the word list
+    pub seen: f64,
+    /// The length in characters of the shortest password that the configuration can generate
+    pub min_length: usize,
+    /// The length in characters of the longest password that the configuration can generate
+    pub max_length: usize,
+    /// The number of words in the word list that the configuration can choose from
+    pub num_candidate_words: usize,
+}
+
+/// Calculates the blind and seen entropy of passwords generated with the given configuration
+/// and word list, returning an error if the configuration is invalid for the word list.
+///
+/// ```
+/// use xkcd_pass::{calculate_entropy, Configuration, SimpleEnglish};
+///
+/// let entropy = calculate_entropy(&Configuration::xkcd(), &SimpleEnglish::new()).unwrap();
+/// assert!(entropy.seen > 0.0);
+/// assert!(entropy.blind_min <= entropy.blind_max);
+/// ```
+pub fn calculate_entropy<A: WordList>(config: &Configuration, word_list: &A) -> Result<Entropy, ConfigError> {
+    config.validate(word_list)?;
+
+    let words = word_list.get_words(config.words.min_length, config.words.max_length);
+    let num_words = config.words.num_words as usize;
+
+    // The shortest and longest words that can be chosen
+    let shortest_word = words.iter().map(|a| a.chars().count()).min().unwrap_or(0);
+    let longest_word = words.iter().map(|a| a.chars().count()).max().unwrap_or(0);
+
+    let (min_length, max_length) = password_length_range(config, shortest_word, longest_word);
+
+    // Blind entropy
+    let alphabet_size = alphabet_size(config, shortest_word) as f64;
+    let blind_min = min_length as f64 * alphabet_size.log2();
+    let blind_max = max_length as f64 * alphabet_size.log2();
+
+    // Seen entropy. There may be no matching words when no words are chosen
+    let mut seen = match num_words {
+        0 => 0.0,
+        _ => num_words as f64 * (words.len() as f64).log2(),
+    };
+
+    // Words may not contain any letters that can be substituted so no entropy is counted for
+    // leetspeak
+    seen += case_entropy(config, &words);
+
+    let has_padding_symbols = has_padding_symbols(config, shortest_word);
+    let seperator_entropy = (config.seperator.seperators.len() as f64).log2();
+    let num_gaps = num_gaps(config);
+    match config.seperator.seperator_type {
+        SeperatorTypes::SingleCharacter => {},
+        // The seperator can only be seen in the gaps or the padding
+        SeperatorTypes::RandomCharacter => {
+            let pads_with_seperator = config.padding_symbols.padding_character_type == PaddingCharTypes::SeperatorCharacter;
+            if num_gaps > 0 || (has_padding_symbols && pads_with_seperator) {
+                seen += seperator_entropy;
+            }
+        },
+        SeperatorTypes::RandomCharacterPerGap => seen += num_gaps as f64 * seperator_entropy,
+    }
+
+    if has_padding_symbols {
+        match config.padding_symbols.padding_character_type {
+            PaddingCharTypes::SingleCharacter => {},
+            PaddingCharTypes::RandomCharacter => seen += (config.padding_symbols.padding_chars.len() as f64).log2(),
+            PaddingCharTypes::SeperatorCharacter => {
+                // The padding seperator is chosen independently of the gaps
+                if let SeperatorTypes::RandomCharacterPerGap = config.seperator.seperator_type {
+                    seen += seperator_entropy;
+                }
+            },
+        }
+    }
+
+    seen += digit_entropy(config.padding_digits.num_before);
+    seen += digit_entropy(config.padding_digits.num_after);
+
+    return Ok(Entropy {
+        blind_min,
+        blind_max,
+        seen,
+        min_length,
+        max_length,
+        num_candidate_words: words.len(),
+    });
+}
+
+/// Calculates the entropy added by the random case transformations.
+///
+/// Only the random case transformations after the last transformation which sets the case of the
+/// words are counted. `CapitaliseFirst` and `AlternatingLowerUpper` only set the case of some of
+/// the letters, but are treated the same so the entropy is never overstated.
+fn case_entropy(config: &Configuration, words: &[&String]) -> f64 {
+    let transformations = &config.words.transformations;
+    let last_reset = transformations.iter().rposition(|a| {
+        matches!(*a, WordTransformations::CapitaliseFirst | WordTransformations::CapitaliseNonFirst
+            | WordTransformations::LowerCase | WordTransformations::UpperCase
+            | WordTransformations::AlternatingLowerUpper)
+    });
+    let random_transformations = &transformations[last_reset.map_or(0, |a| a + 1)..];
+    let num_word_flips = random_transformations.iter().filter(|a| **a == WordTransformations::RandomLowerUpper).count();
+    let num_letter_flips = random_transformations.iter().filter(|a| **a == WordTransformations::RandomCharacterCase).count();
+    if num_word_flips == 0 && num_letter_flips == 0 {
+        return 0.0;
+    }
+
+    // Leetspeak replaces letters with digits, which have no case, whether it comes before or after
+    // the case was chosen
+    let has_leetspeak = transformations.iter().any(|a| matches!(*a, WordTransformations::Leetspeak | WordTransformations::RandomLeetspeak));
+    let cased_letters = words.iter().map(|word| {
+        word.to_lowercase().chars().filter(|&a| a.is_lowercase() && !(has_leetspeak && leetspeak_substitute(a).is_some())).count()
+    }).min().unwrap_or(0);
+
+    // The letters which are still lower case when the random transformations are applied
+    let lower_letters = match last_reset.map(|a| &transformations[a]) {
+        None | Some(&WordTransformations::LowerCase) => cased_letters,
+        Some(&WordTransformations::CapitaliseFirst) => cased_letters.saturating_sub(1),
+        _ => 0,
+    };
+
+    return config.words.num_words as f64 * random_case_entropy(num_word_flips, num_letter_flips, lower_letters);
+}
+
+/// The entropy of the case of a word with `lower_letters` lower case letters after it has been
+/// through `RandomLowerUpper` `num_word_flips` times and `RandomCharacterCase` `num_letter_flips`
+/// times. Both transformations only ever make letters upper case, so repeating them adds less
+/// entropy each time.
+fn random_case_entropy(num_word_flips: usize, num_letter_flips: usize, lower_letters: usize) -> f64 {
+    // The chance that none of the transformations made the word or a letter upper case
+    let word_kept = 0.5f64.powi(num_word_flips as i32);
+    let letter_kept = 0.5f64.powi(num_letter_flips as i32);
+
+    // The word is all upper case if the whole word or every letter was made upper case
+    let all_upper = (1.0 - word_kept) + word_kept * (1.0 - letter_kept).powi(lower_letters as i32);
+    let mut entropy = -all_upper * all_upper.log2();
+
+    // Otherwise some number of letters are still lower case, each combination equally likely
+    let mut combinations = 1.0;
+    for num_lower in 1..=lower_letters {
+        combinations = combinations * (lower_letters - num_lower + 1) as f64 / num_lower as f64;
+        let probability = word_kept * letter_kept.powi(num_lower as i32) * (1.0 - letter_kept).powi((lower_letters - num_lower) as i32);
+        if probability > 0.0 {
+            entropy -= combinations * probability * probability.log2();
+        }
+    }
+    return entropy;
+}
+
+/// Calculates the shortest and longest possible password length given the shortest and longest
+/// words which could be chosen
+fn password_length_range(config: &Configuration, shortest_word: usize, longest_word: usize) -> (usize, usize) {
+    let min_length = unpadded_length(config, shortest_word);
+    let max_length = unpadded_length(config, longest_word);
+
+    return match config.padding_symbols.padding_type {
+        PaddingTypes::Adaptive(pad_length) => {
+            let pad_length = pad_length as usize;
+            (min_length.max(pad_length), max_length.max(pad_length))
+        },
+        PaddingTypes::Fixed(num_pad_before, num_pad_after) => {
+            let padding = num_pad_before as usize + num_pad_after as usize;
+            (min_length + padding, max_length + padding)
+        },
+    };
+}
+
+/// The length of a password made of words of the given length, before the padding symbols are
+/// added
+fn unpadded_length(config: &Configuration, word_length: usize) -> usize {
+    // The seperators and the padding digits
+    let fixed_length = num_gaps(config) + config.padding_digits.num_before as usize + config.padding_digits.num_after as usize;
+    return fixed_length + config.words.num_words as usize * word_length;
+}
+
+/// The number of seperators between the words and the padding digits
+fn num_gaps(config: &Configuration) -> usize {
+    let mut num_gaps = (config.words.num_words as usize).saturating_sub(1);
+    if config.padding_digits.num_before > 0 {
+        num_gaps += 1;
+    }
+    if config.padding_digits.num_after > 0 {
+        num_gaps += 1;
+    }
+    return num_gaps;
+}
+
+/// Calculates the size of the alphabet an attacker would have to brute force, based on the
+/// character classes that can appear in the password
+fn alphabet_size(config: &Configuration, shortest_word: usize) -> u32 {
+    // The words start off in lower case
+    let mut has_lower = true;
+    let mut has_upper = false;
+    let mut has_digit = config.padding_digits.num_before > 0 || config.padding_digits.num_after > 0;
+    for transformation in &config.words.transformations {
+        match *transformation {
+            WordTransformations::CapitaliseFirst | WordTransformations::CapitaliseNonFirst
+            | WordTransformations::AlternatingLowerUpper | WordTransformations::RandomLowerUpper
+            | WordTransformations::RandomCharacterCase => {
+                has_lower = true;
+                has_upper = true;
+            },
+            WordTransformations::LowerCase => {
+                has_lower = true;
+                has_upper = false;
+            },
+            WordTransformations::UpperCase => {
+                has_lower = false;
+                has_upper = true;
+            },
+            WordTransformations::Leetspeak | WordTransformations::RandomLeetspeak => has_digit = true,
+            WordTransformations::Reverse => {},
+        }
+    }
+    let mut has_symbol = false;
+
+    let mut symbols: Vec<char> = config.seperator.seperators.clone();
+    if has_padding_symbols(config, shortest_word) {
+        symbols.extend(config.padding_symbols.padding_chars.iter());
+    }
+    for c in symbols {
+        if c.is_lowercase() {
+            has_lower = true;
+        } else if c.is_uppercase() {
+            has_upper = true;
+        } else if c.is_ascii_digit() {
+            has_digit = true;
+        } else {
+            has_symbol = true;
+        }
+    }
+
+    let mut size = 0;
+    if has_lower {
+        size += LOWERCASE_ALPHABET;
+    }
+    if has_upper {
+        size += UPPERCASE_ALPHABET;
+    }
+    if has_digit {
+        size += DIGIT_ALPHABET;
+    }
+    if has_symbol {
+        size += SYMBOL_ALPHABET;
+    }
+    return size;
+}
+
+/// Whether padding symbols can be added to the password. Adaptive padding is only added when the
+/// password can be shorter than the padding length
+fn has_padding_symbols(config: &Configuration, shortest_word: usize) -> bool {
+    return match config.padding_symbols.padding_type {
+        PaddingTypes::Adaptive(pad_length) => unpadded_length(config, shortest_word) < pad_length as usize,
+        PaddingTypes::Fixed(num_pad_before, num_pad_after) => num_pad_before > 0 || num_pad_after > 0,
+    };
+}
+
+/// The entropy of a random number with the given amount of digits. The first digit is never 0.
+fn digit_entropy(num_digits: u8) -> f64 {
+    if num_digits == 0 {
+        return 0.0;
+    }
+    return 9f64.log2() + (num_digits - 1) as f64 * 10f64.log2();
+}
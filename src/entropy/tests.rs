@@ -0,0 +1,78 @@
+use super::calculate_entropy;
+use super::super::configuration::{ConfigError, Configuration, PaddingTypes, WordTransformations};
+use super::super::word_list::SimpleEnglish;
+
+#[test]
+pub fn test_entropy_of_invalid_configuration() {
+    let word_list = SimpleEnglish::new();
+
+    let mut config = Configuration::default();
+    config.words.min_length = 40;
+    config.words.max_length = 50;
+    assert_eq!(calculate_entropy(&config, &word_list), Err(ConfigError::NoMatchingWords { min_length: 40, max_length: 50 }));
+
+    let mut config = Configuration::default();
+    config.seperator.seperators = Vec::new();
+    assert_eq!(calculate_entropy(&config, &word_list), Err(ConfigError::NoSeperators));
+}
+
+#[test]
+pub fn test_random_case_entropy() {
+    let word_list = SimpleEnglish::new();
+    let seen = |transformations: Vec<WordTransformations>| {
+        let mut config = Configuration::default();
+        config.words.transformations = transformations;
+        return calculate_entropy(&config, &word_list).unwrap().seen;
+    };
+    let num_words = Configuration::default().words.num_words as f64;
+    let lower_case = seen(vec![WordTransformations::LowerCase]);
+
+    // A later transformation which sets the case removes the randomness
+    assert_eq!(seen(vec![WordTransformations::RandomLowerUpper, WordTransformations::LowerCase]), lower_case);
+    assert_eq!(seen(vec![WordTransformations::RandomCharacterCase, WordTransformations::UpperCase]), lower_case);
+    assert_eq!(seen(vec![WordTransformations::UpperCase, WordTransformations::RandomLowerUpper]), lower_case);
+
+    // Each word is either upper or lower case, and repeating the transformation adds less than a bit
+    assert_eq!(seen(vec![WordTransformations::RandomLowerUpper]), lower_case + num_words);
+    let repeated = seen(vec![WordTransformations::RandomLowerUpper, WordTransformations::RandomLowerUpper]);
+    assert!(repeated > lower_case && repeated < lower_case + num_words);
+
+    // Letters replaced by leetspeak have no case
+    let random_case = seen(vec![WordTransformations::RandomCharacterCase]);
+    let leetspeak = seen(vec![WordTransformations::Leetspeak, WordTransformations::RandomCharacterCase]);
+    assert!(leetspeak < random_case);
+    assert_eq!(leetspeak, seen(vec![WordTransformations::RandomCharacterCase, WordTransformations::Leetspeak]));
+}
+
+#[test]
+pub fn test_no_words() {
+    let mut config = Configuration::xkcd();
+    config.words.num_words = 0;
+    config.words.min_length = 40;
+    config.words.max_length = 50;
+    let entropy = calculate_entropy(&config, &SimpleEnglish::new()).unwrap();
+    assert_eq!(entropy.seen, 0.0);
+    assert_eq!(entropy.num_candidate_words, 0);
+}
+
+#[test]
+pub fn test_unseen_seperators_and_padding() {
+    let word_list = SimpleEnglish::new();
+
+    // A single word has no gaps for the random seperator
+    let mut config = Configuration::xkcd();
+    config.words.num_words = 1;
+    config.words.transformations.clear();
+    let one_word = calculate_entropy(&config, &word_list).unwrap().seen;
+    config.seperator.seperators = vec!['-'];
+    assert_eq!(one_word, calculate_entropy(&config, &word_list).unwrap().seen);
+
+    // Passwords from the wifi preset are at least 39 characters long, so are never padded to 30
+    let mut config = Configuration::wifi();
+    let padded = calculate_entropy(&config, &word_list).unwrap().seen;
+    config.padding_symbols.padding_type = PaddingTypes::Adaptive(30);
+    let unpadded = calculate_entropy(&config, &word_list).unwrap().seen;
+    config.padding_symbols.padding_type = PaddingTypes::Fixed(0, 0);
+    assert_eq!(unpadded, calculate_entropy(&config, &word_list).unwrap().seen);
+    assert!(padded > unpadded);
+}
@@ -1,12 +1,106 @@
 
-use std::ascii::AsciiExt;
-use std::iter::repeat;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Write;
+use std::iter::repeat_n;
 use rand::{Rng, thread_rng};
-use super::configuration::{Configuration, WordTransformations, SeperatorTypes, PaddingCharTypes, PaddingTypes};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+use super::configuration::{Configuration, ConfigError, WordTransformations, SeperatorTypes, PaddingCharTypes, PaddingTypes};
 use super::word_list::WordList;
 
+#[cfg(test)]
+mod tests;
+
+/// The letters replaced by the leetspeak word transformations and their substitutes
+pub const LEETSPEAK_SUBSTITUTIONS: &[(char, char)] = &[
+    ('a', '4'), ('b', '8'), ('e', '3'), ('g', '9'), ('i', '1'), ('o', '0'), ('s', '5'), ('t', '7'), ('z', '2'),
+];
+
+/// The reasons a password could not be generated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GenerateError {
+    /// The configuration cannot be used with the word list
+    InvalidConfiguration(ConfigError),
+    /// Not enough unique passwords could be generated for a batch
+    NotEnoughUniquePasswords {
+        /// The number of passwords requested
+        requested: usize,
+        /// The number of unique passwords which were generated
+        generated: usize,
+    },
+    /// The configuration can make fewer sets of words than the number of passwords requested in
+    /// a batch where no two passwords use the same words
+    NotEnoughWordSets {
+        /// The number of passwords requested
+        requested: usize,
+        /// The number of different sets of words the configuration can make
+        possible: u128,
+    },
+    /// No password with the minimum strength score was generated, requires the `strength`
+    /// feature
+    #[cfg(feature = "strength")]
+    TooWeak {
+        /// The minimum score
+        min_score: u8,
+        /// The number of passwords which were generated
+        attempts: usize,
+    },
+    /// The minimum strength score is higher than `strength::MAX_SCORE`, so no password can reach
+    /// it. Contains the minimum score. Requires the `strength` feature
+    #[cfg(feature = "strength")]
+    InvalidMinScore(u8),
+}
+
+impl fmt::Display for GenerateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match *self {
+            GenerateError::InvalidConfiguration(ref err) => write!(f, "invalid configuration: {}", err),
+            GenerateError::NotEnoughUniquePasswords { requested, generated } => {
+                write!(f, "{} unique passwords were requested but only {} could be generated", requested, generated)
+            },
+            GenerateError::NotEnoughWordSets { requested, possible } => {
+                write!(f, "{} passwords with different words were requested but the configuration can only make {}", requested, possible)
+            },
+            #[cfg(feature = "strength")]
+            GenerateError::TooWeak { min_score, attempts } => {
+                write!(f, "none of the {} generated passwords had a strength score of at least {}", attempts, min_score)
+            },
+            #[cfg(feature = "strength")]
+            GenerateError::InvalidMinScore(min_score) => {
+                write!(f, "the minimum strength score ({}) is higher than the maximum score ({})", min_score, super::strength::MAX_SCORE)
+            },
+        };
+    }
+}
+
+impl Error for GenerateError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        return match *self {
+            GenerateError::InvalidConfiguration(ref err) => Some(err),
+            GenerateError::NotEnoughUniquePasswords { .. } | GenerateError::NotEnoughWordSets { .. } => None,
+            #[cfg(feature = "strength")]
+            GenerateError::TooWeak { .. } | GenerateError::InvalidMinScore(_) => None,
+        };
+    }
+}
+
+impl From<ConfigError> for GenerateError {
+    fn from(err: ConfigError) -> GenerateError {
+        return GenerateError::InvalidConfiguration(err);
+    }
+}
+
 /// Generates a password with the given configuration and word list.
 ///
+/// The thread-local random number generator is used. To supply a different random number
+/// generator, use `generate_password_with_rng`.
+///
+/// # Panics
+///
+/// Panics if the configuration is invalid for the word list. Use `try_generate_password` to
+/// handle an invalid configuration instead.
+///
 /// ```
 /// use xkcd_pass::{generate_password, Configuration, SimpleEnglish};
 ///
@@ -17,89 +111,126 @@ use super::word_list::WordList;
 /// println!("Password: {}", password);
 /// ```
 pub fn generate_password<A: WordList>(config: &Configuration, word_list: &A) -> String {
-    // TODO: Accept different random generators
-    let mut rgen = thread_rng();
+    return generate_password_with_rng(config, word_list, &mut thread_rng());
+}
 
-    // Generate the words
-    let word_list = word_list.get_words(config.words.min_length, config.words.max_length);
-    let num_words = config.words.num_words as usize;
-    let mut chosen_words = Vec::with_capacity(num_words);
+/// Generates a password with the given configuration and word list, drawing all random choices
+/// from the given random number generator.
+///
+/// # Panics
+///
+/// Panics if the configuration is invalid for the word list. Use
+/// `try_generate_password_with_rng` to handle an invalid configuration instead.
+///
+/// ```
+/// extern crate rand;
+/// extern crate xkcd_pass;
+///
+/// use rand::{SeedableRng, StdRng};
+/// use xkcd_pass::{generate_password_with_rng, Configuration, SimpleEnglish};
+///
+/// # fn main() {
+/// let config = Configuration::default();
+/// let word_list = SimpleEnglish::new();
+/// // The same seed always generates the same password
+/// let seed: &[_] = &[1, 2, 3, 4];
+/// let first = generate_password_with_rng(&config, &word_list, &mut StdRng::from_seed(seed));
+/// let second = generate_password_with_rng(&config, &word_list, &mut StdRng::from_seed(seed));
+/// assert_eq!(first, second);
+/// # }
+/// ```
+pub fn generate_password_with_rng<A: WordList, R: Rng>(config: &Configuration, word_list: &A, rgen: &mut R) -> String {
+    return match try_generate_password_with_rng(config, word_list, rgen) {
+        Ok(pass) => pass,
+        Err(err) => panic!("{}", err),
+    };
+}
 
+/// Generates a password with the given configuration and word list, returning an error instead
+/// of panicking if the configuration is invalid.
+///
+/// ```
+/// use xkcd_pass::{try_generate_password, Configuration, SimpleEnglish};
+///
+/// let word_list = SimpleEnglish::new();
+/// let mut config = Configuration::default();
+/// assert!(try_generate_password(&config, &word_list).is_ok());
+///
+/// // No words are this long
+/// config.words.min_length = 100;
+/// config.words.max_length = 120;
+/// assert!(try_generate_password(&config, &word_list).is_err());
+/// ```
+pub fn try_generate_password<A: WordList>(config: &Configuration, word_list: &A) -> Result<String, GenerateError> {
+    return try_generate_password_with_rng(config, word_list, &mut thread_rng());
+}
+
+/// Generates a password with the given configuration, word list and random number generator,
+/// returning an error instead of panicking if the configuration is invalid.
+pub fn try_generate_password_with_rng<A: WordList, R: Rng>(config: &Configuration, word_list: &A, rgen: &mut R) -> Result<String, GenerateError> {
+    config.validate(word_list)?;
+    return Ok(build_password(config, word_list, rgen));
+}
+
+/// Builds the password from a configuration which has already been validated
+pub(crate) fn build_password<A: WordList, R: Rng>(config: &Configuration, word_list: &A, rgen: &mut R) -> String {
+    let chosen_words = choose_config_words(config, word_list, rgen);
+    return assemble_password(config, chosen_words, rgen);
+}
+
+/// Randomly chooses the words for a password from a configuration which has already been
+/// validated
+pub(crate) fn choose_config_words<A: WordList, R: Rng>(config: &Configuration, word_list: &A, rgen: &mut R) -> Vec<String> {
+    return choose_list_words(word_list, config.words.min_length, config.words.max_length, config.words.num_words as usize, rgen);
+}
+
+/// Randomly chooses the given number of words between the minimum and maximum length. The word
+/// list must have at least one word of the right length
+pub(crate) fn choose_list_words<A: WordList, R: Rng>(word_list: &A, min_length: u8, max_length: u8, num_words: usize, rgen: &mut R) -> Vec<String> {
+    // Borrow the words from the word list when it is indexed by length
+    return match word_list.get_words_slice(min_length, max_length) {
+        Some(words) => choose_words(words, num_words, rgen),
+        None => choose_words(&word_list.get_words(min_length, max_length), num_words, rgen),
+    };
+}
+
+/// Randomly chooses the given number of words
+fn choose_words<S: AsRef<str>, R: Rng>(words: &[S], num_words: usize, rgen: &mut R) -> Vec<String> {
+    let mut chosen_words = Vec::with_capacity(num_words);
     for _ in 0..num_words {
-        let random_index = rgen.gen_range(0, word_list.len());
-        chosen_words.push(word_list[random_index].clone());
+        let random_index = rgen.gen_range(0, words.len());
+        chosen_words.push(words[random_index].as_ref().to_string());
     }
+    return chosen_words;
+}
+
+/// Transforms the chosen words and joins them together with the seperators, padding digits and
+/// padding symbols. The configuration must already have been validated.
+///
+/// The password is written into a buffer which is allocated once with enough capacity, and every
+/// intermediate string is wiped with the `zeroize` feature, so no copies of the password are left
+/// in freed memory.
+pub(crate) fn assemble_password<R: Rng>(config: &Configuration, mut chosen_words: Vec<String>, rgen: &mut R) -> String {
+    let num_words = chosen_words.len();
+
     // Lowercase all the words
-    for i in 0..chosen_words.len() {
-        chosen_words[i] = to_lower_case(&chosen_words[i]);
+    for word in chosen_words.iter_mut() {
+        transform_word(word, push_lower_case);
     }
 
-    // Modify the case of the words
-    match config.words.transformations {
-        WordTransformations::CapitaliseFirst => {
-            for i in 0..chosen_words.len() {
-                let final_word: String = {
-                    let word = &chosen_words[i];
-                    let mut word_iter = word.chars();
-                    let mut first_char = word_iter.next().unwrap();
-                    first_char = first_char.to_ascii_uppercase();
-                    let rest_of_word: String = word_iter.collect();
-                    let mut final_str = first_char.to_string();
-                    final_str.push_str(&rest_of_word);
-                    final_str
-                };
-                chosen_words[i] = final_word;
-            }
-        },
-        WordTransformations::CapitaliseNonFirst => {
-            for i in 0..chosen_words.len() {
-                let final_word: String = {
-                    let word = &chosen_words[i];
-                    let mut word_iter = word.chars();
-                    let mut first_char = word_iter.next().unwrap();
-                    first_char = first_char.to_ascii_lowercase();
-                    let rest_of_word: String = word_iter.map(|a| a.to_ascii_uppercase()).collect();
-                    let mut final_str = first_char.to_string();
-                    final_str.push_str(&rest_of_word);
-                    final_str
-                };
-                chosen_words[i] = final_word;
-            }
-        },
-        WordTransformations::LowerCase => {
-            // Do nothing since all the words are already lower case
-        },
-        WordTransformations::UpperCase => {
-            for i in 0..chosen_words.len() {
-                chosen_words[i] = to_upper_case(&chosen_words[i]);
-            }
-        },
-        WordTransformations::AlternatingLowerUpper => {
-            for i in 0..chosen_words.len() {
-                if i % 2 == 1 {
-                    // Make every second word upper case
-                    chosen_words[i] = to_upper_case(&chosen_words[i]);
-                }
-            }
-        },
-        WordTransformations::RandomLowerUpper => {
-            for i in 0..chosen_words.len() {
-                if rgen.gen() {
-                    // Make every second word upper case
-                    chosen_words[i] = to_upper_case(&chosen_words[i]);
-                }
-            }
-        },
+    // Transform the words in order
+    for transformation in &config.words.transformations {
+        apply_transformation(transformation, &mut chosen_words, rgen);
     }
 
-    // Determine the seperator character
+    // Determine the seperator character. When a seperator is chosen for each gap, this character
+    // is only used for the padding
     let num_seperators = config.seperator.seperators.len();
-    assert!(num_seperators > 0);
     let sep_char = match config.seperator.seperator_type {
         SeperatorTypes::SingleCharacter => {
             config.seperator.seperators[0]
         }
-        SeperatorTypes::RandomCharacter => {
+        SeperatorTypes::RandomCharacter | SeperatorTypes::RandomCharacterPerGap => {
             let random_index = rgen.gen_range(0, num_seperators);
             config.seperator.seperators[random_index]
         }
@@ -109,11 +240,9 @@ pub fn generate_password<A: WordList>(config: &Configuration, word_list: &A) ->
     let num_pad_chars = config.padding_symbols.padding_chars.len();
     let pad_char = match config.padding_symbols.padding_character_type {
         PaddingCharTypes::SingleCharacter => {
-            assert!(num_pad_chars > 0);
             config.padding_symbols.padding_chars[0]
         },
         PaddingCharTypes::RandomCharacter => {
-            assert!(num_pad_chars > 0);
             let random_index = rgen.gen_range(0, num_pad_chars);
             config.padding_symbols.padding_chars[random_index]
         },
@@ -125,65 +254,211 @@ pub fn generate_password<A: WordList>(config: &Configuration, word_list: &A) ->
     // Determine the digits before and after
     let num_digits_before = config.padding_digits.num_before;
     let num_digits_after = config.padding_digits.num_after;
-    let mut digits_before_str = String::new();
-    let mut digits_after_str = String::new();
+    let mut digits_before = 0;
+    let mut digits_after = 0;
+    if num_digits_before > 0 {
+        digits_before = rgen.gen_range(10u64.pow((num_digits_before - 1) as u32), 10u64.pow(num_digits_before as u32));
+    }
+    if num_digits_after > 0 {
+        digits_after = rgen.gen_range(10u64.pow((num_digits_after - 1) as u32), 10u64.pow(num_digits_after as u32));
+    }
+
+    // Determine the seperators between the words and digits
+    let mut num_gaps = num_words.saturating_sub(1);
     if num_digits_before > 0 {
-        let digits_before = rgen.gen_range(10u64.pow((num_digits_before - 1) as u32), 10u64.pow(num_digits_before as u32));
-        digits_before_str = digits_before.to_string();
+        num_gaps += 1;
     }
     if num_digits_after > 0 {
-        let digits_after = rgen.gen_range(10u64.pow((num_digits_after - 1) as u32), 10u64.pow(num_digits_after as u32));
-        digits_after_str = digits_after.to_string();
+        num_gaps += 1;
     }
+    let mut gap_chars: Vec<char> = match config.seperator.seperator_type {
+        SeperatorTypes::RandomCharacterPerGap => {
+            (0..num_gaps).map(|_| config.seperator.seperators[rgen.gen_range(0, num_seperators)]).collect()
+        },
+        _ => repeat_n(sep_char, num_gaps).collect(),
+    };
 
     // Build the final password
 
+    // Allocate enough space for the whole password up front, so the buffer is never moved. Count
+    // characters rather than bytes for adaptive padding, so accented letters count once
+    let num_chars = chosen_words.iter().map(|a| a.chars().count()).sum::<usize>()
+        + num_gaps + num_digits_before as usize + num_digits_after as usize;
+    let num_pad = match config.padding_symbols.padding_type {
+        PaddingTypes::Adaptive(min_length) => (min_length as usize).saturating_sub(num_chars), // TODO: Use usize in config ?
+        PaddingTypes::Fixed(num_pad_before, num_pad_after) => num_pad_before as usize + num_pad_after as usize,
+    };
+    let capacity = chosen_words.iter().map(|a| a.len()).sum::<usize>()
+        + gap_chars.iter().map(|a| a.len_utf8()).sum::<usize>()
+        + num_digits_before as usize + num_digits_after as usize
+        + num_pad * pad_char.len_utf8();
+    let mut pass = String::with_capacity(capacity);
+    let initial_capacity = pass.capacity();
+
+    if let PaddingTypes::Fixed(num_pad_before, _) = config.padding_symbols.padding_type {
+        pass.extend(repeat_n(pad_char, num_pad_before as usize));
+    }
+
     // Generate the string without the padding
-    let mut pass = String::from("");
+    let mut gaps = gap_chars.iter();
     if num_digits_before > 0 {
-        let temp_dig = format!("{}{}", digits_before_str, sep_char);
-        pass.push_str(&temp_dig);
+        // Writing to a string cannot fail
+        write!(pass, "{}", digits_before).unwrap();
+        pass.push(*gaps.next().unwrap());
     }
 
     for (i, word) in chosen_words.iter().enumerate() {
         if i != 0 {
-            pass.push_str(&sep_char.to_string());
+            pass.push(*gaps.next().unwrap());
         }
-        pass.push_str(&word);
+        pass.push_str(word);
     }
 
     if num_digits_after > 0 {
-        let temp_dig = format!("{}{}", sep_char, digits_after_str);
-        pass.push_str(&temp_dig);
+        pass.push(*gaps.next().unwrap());
+        write!(pass, "{}", digits_after).unwrap();
     }
 
     // Generate the padding
     match config.padding_symbols.padding_type {
-        PaddingTypes::Adaptive(min_length) => {
-            let pass_length = pass.len();
-            let min_length = min_length as usize; // TODO: Use usize in config ?
-            if min_length > pass_length {
-                let needed_padding = min_length - pass_length;
-                let end_pad: String = repeat(pad_char).take(needed_padding).collect();
-
-                pass = format!("{}{}", pass, end_pad);
-            }
+        PaddingTypes::Adaptive(_) => {
+            pass.extend(repeat_n(pad_char, num_pad));
         },
-        PaddingTypes::Fixed(num_pad_before, num_pad_after) => {
-            let begin_pad: String = repeat(pad_char).take(num_pad_before as usize).collect();
-            let end_pad: String = repeat(pad_char).take(num_pad_after as usize).collect();
-
-            pass = format!("{}{}{}", begin_pad, pass, end_pad);
+        PaddingTypes::Fixed(_, num_pad_after) => {
+            pass.extend(repeat_n(pad_char, num_pad_after as usize));
         },
     }
+    debug_assert_eq!(pass.capacity(), initial_capacity, "the password buffer was moved");
+
+    for word in chosen_words.iter_mut() {
+        wipe(word);
+    }
+    wipe(&mut gap_chars);
 
     return pass;
 }
 
-fn to_lower_case(s: &str) -> String {
-    return s.to_ascii_lowercase();
+/// Applies a single word transformation to all of the chosen words
+fn apply_transformation<R: Rng>(transformation: &WordTransformations, chosen_words: &mut [String], rgen: &mut R) {
+    match *transformation {
+        WordTransformations::CapitaliseFirst => {
+            for word in chosen_words.iter_mut() {
+                transform_word(word, |word, capitalised| {
+                    // A custom word list can contain empty words, which are left as they are
+                    let mut word_iter = word.chars();
+                    if let Some(first_char) = word_iter.next() {
+                        capitalised.extend(first_char.to_uppercase());
+                    }
+                    capitalised.push_str(word_iter.as_str());
+                });
+            }
+        },
+        WordTransformations::CapitaliseNonFirst => {
+            for word in chosen_words.iter_mut() {
+                transform_word(word, |word, capitalised| {
+                    let mut word_iter = word.chars();
+                    if let Some(first_char) = word_iter.next() {
+                        capitalised.extend(first_char.to_lowercase());
+                    }
+                    capitalised.extend(word_iter.flat_map(|a| a.to_uppercase()));
+                });
+            }
+        },
+        WordTransformations::LowerCase => {
+            for word in chosen_words.iter_mut() {
+                transform_word(word, push_lower_case);
+            }
+        },
+        WordTransformations::UpperCase => {
+            for word in chosen_words.iter_mut() {
+                transform_word(word, |word, upper| upper.extend(word.chars().flat_map(|a| a.to_uppercase())));
+            }
+        },
+        WordTransformations::AlternatingLowerUpper => {
+            for word in chosen_words.iter_mut().skip(1).step_by(2) {
+                // Make every second word upper case
+                transform_word(word, |word, upper| upper.extend(word.chars().flat_map(|a| a.to_uppercase())));
+            }
+        },
+        WordTransformations::RandomLowerUpper => {
+            for word in chosen_words.iter_mut() {
+                if rgen.gen() {
+                    // Randomly make the word upper case
+                    transform_word(word, |word, upper| upper.extend(word.chars().flat_map(|a| a.to_uppercase())));
+                }
+            }
+        },
+        WordTransformations::RandomCharacterCase => {
+            for word in chosen_words.iter_mut() {
+                transform_word(word, |word, final_word| {
+                    for a in word.chars() {
+                        if rgen.gen() {
+                            final_word.extend(a.to_uppercase());
+                        } else {
+                            final_word.push(a);
+                        }
+                    }
+                });
+            }
+        },
+        WordTransformations::Leetspeak => {
+            for word in chosen_words.iter_mut() {
+                transform_word(word, |word, leet| leet.extend(word.chars().map(|a| leetspeak_substitute(a).unwrap_or(a))));
+            }
+        },
+        WordTransformations::RandomLeetspeak => {
+            for word in chosen_words.iter_mut() {
+                transform_word(word, |word, leet| {
+                    leet.extend(word.chars().map(|a| {
+                        match leetspeak_substitute(a) {
+                            // Only use randomness for letters which can be substituted
+                            Some(substitute) if rgen.gen() => substitute,
+                            _ => a,
+                        }
+                    }));
+                });
+            }
+        },
+        WordTransformations::Reverse => {
+            for word in chosen_words.iter_mut() {
+                transform_word(word, |word, reversed| reversed.extend(word.chars().rev()));
+            }
+        },
+    }
 }
 
-fn to_upper_case(s: &str) -> String {
-    return s.to_ascii_uppercase();
+/// Replaces a word with the string the transformation writes. The new string has enough capacity
+/// that it is never moved, since changing the case of a character can at most triple its length in
+/// bytes, and the old word is wiped
+fn transform_word<F: FnOnce(&str, &mut String)>(word: &mut String, transformation: F) {
+    let mut transformed = String::with_capacity(word.len() * 3);
+    transformation(word, &mut transformed);
+    wipe(word);
+    *word = transformed;
+}
+
+/// Writes the word in lower case. `str::to_lowercase` is used since it handles a final sigma, and
+/// its result is wiped
+fn push_lower_case(word: &str, lower: &mut String) {
+    let mut lower_case = word.to_lowercase();
+    lower.push_str(&lower_case);
+    wipe(&mut lower_case);
+}
+
+/// Overwrites the memory of a value which held part of a password, requires the `zeroize`
+/// feature and does nothing without it
+#[cfg(feature = "zeroize")]
+pub(crate) fn wipe<Z: Zeroize + ?Sized>(value: &mut Z) {
+    value.zeroize();
+}
+
+/// Overwrites the memory of a value which held part of a password, requires the `zeroize`
+/// feature and does nothing without it
+#[cfg(not(feature = "zeroize"))]
+pub(crate) fn wipe<Z: ?Sized>(_value: &mut Z) {}
+
+/// Finds the leetspeak substitute for a letter
+pub(crate) fn leetspeak_substitute(c: char) -> Option<char> {
+    let c = c.to_ascii_lowercase();
+    return LEETSPEAK_SUBSTITUTIONS.iter().find(|&&(letter, _)| letter == c).map(|&(_, substitute)| substitute);
 }
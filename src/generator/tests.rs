@@ -0,0 +1,147 @@
+use rand::{SeedableRng, StdRng};
+use super::{generate_password_with_rng, try_generate_password, GenerateError};
+use super::super::configuration::{Configuration, ConfigError, SeperatorTypes, PaddingTypes, PaddingCharTypes, WordTransformations};
+use super::super::word_list::{SimpleEnglish, FileWordList, FileWordListOptions, WordList};
+
+/// A word list with only an empty word, which `FileWordList` never contains
+struct EmptyWord(Vec<String>);
+
+impl WordList for EmptyWord {
+    fn get_words(&self, min_len: u8, _max_len: u8) -> Vec<&String> {
+        let EmptyWord(ref words) = *self;
+        return words.iter().filter(|_| min_len == 0).collect();
+    }
+}
+
+#[test]
+pub fn test_seeded_generation_is_repeatable() {
+    let config = Configuration::default();
+    let word_list = SimpleEnglish::new();
+    let seed: &[_] = &[42];
+
+    let first = generate_password_with_rng(&config, &word_list, &mut StdRng::from_seed(seed));
+    let second = generate_password_with_rng(&config, &word_list, &mut StdRng::from_seed(seed));
+    assert_eq!(first, second);
+}
+
+#[test]
+pub fn test_invalid_configuration_is_an_error() {
+    let mut config = Configuration::default();
+    config.seperator.seperators.clear();
+
+    let result = try_generate_password(&config, &SimpleEnglish::new());
+    assert_eq!(result, Err(GenerateError::InvalidConfiguration(ConfigError::NoSeperators)));
+}
+
+#[test]
+pub fn test_capitalise_empty_word() {
+    let word_list = EmptyWord(vec![String::new()]);
+    let mut config = Configuration::xkcd();
+    config.words.num_words = 2;
+    config.words.min_length = 0;
+    let seed: &[_] = &[1];
+
+    for transformation in [WordTransformations::CapitaliseFirst, WordTransformations::CapitaliseNonFirst] {
+        config.words.transformations = vec![transformation];
+        assert_eq!(generate_password_with_rng(&config, &word_list, &mut StdRng::from_seed(seed)), "-");
+    }
+}
+
+#[test]
+pub fn test_seperator_per_gap() {
+    let mut config = Configuration::xkcd();
+    config.words.num_words = 6;
+    config.seperator.seperator_type = SeperatorTypes::RandomCharacterPerGap;
+    config.seperator.seperators = vec!['-', '+', '='];
+    config.padding_digits.num_before = 2;
+    config.padding_symbols.padding_type = PaddingTypes::Fixed(0, 0);
+    let word_list = SimpleEnglish::new();
+    let mut rgen = StdRng::from_seed(&[7][..]);
+
+    let mut found_mixed = false;
+    for _ in 0..20 {
+        let password = generate_password_with_rng(&config, &word_list, &mut rgen);
+        let seperators: Vec<char> = password.chars().filter(|a| config.seperator.seperators.contains(a)).collect();
+        // 5 gaps between the words and 1 gap after the digits
+        assert_eq!(seperators.len(), 6);
+        if seperators.iter().any(|&a| a != seperators[0]) {
+            found_mixed = true;
+        }
+    }
+    assert!(found_mixed);
+}
+
+#[test]
+pub fn test_fixed_padding() {
+    let mut config = Configuration::default();
+    config.padding_symbols.padding_type = PaddingTypes::Fixed(3, 1);
+    config.padding_symbols.padding_character_type = PaddingCharTypes::SingleCharacter;
+    config.padding_symbols.padding_chars = vec!['#'];
+
+    let password = generate_password_with_rng(&config, &SimpleEnglish::new(), &mut StdRng::from_seed(&[1][..]));
+    assert!(password.starts_with("###"));
+    assert!(!password.starts_with("####"));
+    assert!(password.ends_with('#'));
+}
+
+#[test]
+pub fn test_leetspeak() {
+    let mut config = Configuration::xkcd();
+    config.words.transformations = vec![WordTransformations::Leetspeak];
+    let word_list = SimpleEnglish::new();
+    let mut rgen = StdRng::from_seed(&[3][..]);
+
+    for _ in 0..20 {
+        let password = generate_password_with_rng(&config, &word_list, &mut rgen);
+        // Every letter with a substitute has been replaced
+        assert!(!password.chars().any(|a| "abegiosz".contains(a)));
+    }
+}
+
+#[test]
+pub fn test_random_character_case() {
+    let mut config = Configuration::xkcd();
+    config.words.num_words = 6;
+    config.words.transformations = vec![WordTransformations::RandomCharacterCase];
+    let password = generate_password_with_rng(&config, &SimpleEnglish::new(), &mut StdRng::from_seed(&[5][..]));
+
+    assert!(password.chars().any(|a| a.is_ascii_uppercase()));
+    assert!(password.chars().any(|a| a.is_ascii_lowercase()));
+}
+
+#[test]
+pub fn test_transformations_are_applied_in_order() {
+    let mut config = Configuration::xkcd();
+    config.words.num_words = 1;
+    config.padding_digits.num_before = 0;
+    config.padding_digits.num_after = 0;
+    config.words.transformations = vec![WordTransformations::CapitaliseFirst, WordTransformations::Reverse];
+    let password = generate_password_with_rng(&config, &SimpleEnglish::new(), &mut StdRng::from_seed(&[9][..]));
+
+    // The capital letter ends up at the end of the word
+    let mut chars = password.chars();
+    assert!(chars.next_back().unwrap().is_ascii_uppercase());
+    assert!(chars.all(|a| a.is_ascii_lowercase()));
+}
+
+#[test]
+pub fn test_unicode_words() {
+    let word_list = FileWordList::from_reader("ÄRGER\nÉLAN".as_bytes(), &FileWordListOptions::default()).unwrap();
+    let mut config = Configuration::default();
+    config.words.num_words = 2;
+    config.words.min_length = 4;
+    config.words.max_length = 5;
+    config.words.transformations = vec![WordTransformations::CapitaliseNonFirst];
+    config.seperator.seperator_type = SeperatorTypes::SingleCharacter;
+    config.seperator.seperators = vec!['-'];
+    config.padding_digits.num_before = 0;
+    config.padding_digits.num_after = 0;
+    config.padding_symbols.padding_type = PaddingTypes::Adaptive(20);
+    config.padding_symbols.padding_character_type = PaddingCharTypes::SingleCharacter;
+    config.padding_symbols.padding_chars = vec!['!'];
+
+    let password = try_generate_password(&config, &word_list).unwrap();
+    // The accented letters are upper cased and the padding counts characters rather than bytes
+    assert!(password.starts_with("äRGER") || password.starts_with("éLAN"));
+    assert_eq!(password.chars().count(), 20);
+}
@@ -12,14 +12,42 @@
 //! println!("Password: {}", password);
 //! ```
 
+// The explicit `return` style is used throughout the crate
+#![allow(clippy::needless_return)]
+
 extern crate rand;
 extern crate serde;
 #[macro_use] extern crate serde_derive;
+#[cfg(feature = "json")] extern crate serde_json;
+#[cfg(feature = "toml")] extern crate toml;
+#[cfg(feature = "yaml")] extern crate serde_yaml;
+#[cfg(feature = "pbkdf2")] extern crate pbkdf2;
+#[cfg(feature = "pbkdf2")] extern crate sha2;
+#[cfg(feature = "scrypt")] extern crate scrypt;
+#[cfg(feature = "argon2")] extern crate argon2;
+#[cfg(feature = "strength")] extern crate zxcvbn;
+#[cfg(feature = "zeroize")] extern crate zeroize;
 
 pub mod generator;
 pub mod configuration;
 pub mod word_list;
+pub mod entropy;
+pub mod dice;
+pub mod batch;
+pub mod policy;
+pub mod answer;
+#[cfg(any(feature = "pbkdf2", feature = "scrypt", feature = "argon2"))]
+pub mod derive;
+#[cfg(feature = "strength")]
+pub mod strength;
+#[cfg(feature = "zeroize")]
+pub mod secret;
 
-pub use generator::generate_password;
+pub use generator::{generate_password, generate_password_with_rng, try_generate_password, try_generate_password_with_rng};
 pub use configuration::Configuration;
 pub use word_list::SimpleEnglish;
+pub use entropy::calculate_entropy;
+pub use dice::generate_password_from_dice;
+pub use batch::{generate_passwords, try_generate_passwords, try_generate_passwords_with_rng};
+pub use policy::{try_generate_password_with_policy, try_generate_password_with_policy_with_rng};
+pub use answer::{generate_answer, try_generate_answer};
@@ -0,0 +1,354 @@
+//! Password policies, such as the password rules of a website, and generating passwords which
+//! satisfy them.
+//!
+//! A policy can be written by hand or parsed from a website's `passwordrules` attribute with
+//! `PasswordPolicy::from_password_rules`.
+//!
+//! # Example
+//!
+//! ```
+//! use xkcd_pass::{try_generate_password_with_policy, Configuration, SimpleEnglish};
+//! use xkcd_pass::policy::{CharacterClass, PasswordPolicy};
+//!
+//! // At least one uppercase letter, one digit and one symbol, at most 24 characters and no '&'
+//! let policy = PasswordPolicy {
+//!     max_length: Some(24),
+//!     required: vec![CharacterClass::Upper, CharacterClass::Digit, CharacterClass::Symbol],
+//!     forbidden: vec!['&'],
+//!     ..PasswordPolicy::default()
+//! };
+//!
+//! let mut config = Configuration::default();
+//! config.words.num_words = 2;
+//! let password = try_generate_password_with_policy(&config, &SimpleEnglish::new(), &policy).unwrap();
+//! assert_eq!(policy.check(&password), Ok(()));
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use rand::{Rng, thread_rng};
+
+use super::configuration::{Configuration, ConfigError, WordTransformations, PaddingTypes};
+use super::entropy::calculate_entropy;
+use super::generator::{generate_password_with_rng, wipe};
+use super::word_list::WordList;
+
+mod rules;
+#[cfg(test)]
+mod tests;
+
+pub use self::rules::PasswordRulesError;
+
+/// The number of passwords generated before a policy is considered unsatisfiable
+const MAX_ATTEMPTS: usize = 1000;
+
+/// A class of characters which a policy can require
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CharacterClass {
+    /// The ASCII lowercase letters `a` to `z`
+    Lower,
+    /// The ASCII uppercase letters `A` to `Z`
+    Upper,
+    /// The ASCII digits `0` to `9`
+    Digit,
+    /// The printable ASCII characters which are not letters or digits, including space
+    Symbol,
+    /// Any of the given characters
+    Custom(Vec<char>),
+}
+
+impl CharacterClass {
+    /// Whether the character is in the class
+    ///
+    /// ```
+    /// use xkcd_pass::policy::CharacterClass;
+    ///
+    /// assert!(CharacterClass::Symbol.contains('!'));
+    /// assert!(!CharacterClass::Upper.contains('É'));
+    /// assert!(CharacterClass::Custom(vec!['#', '@']).contains('@'));
+    /// ```
+    pub fn contains(&self, c: char) -> bool {
+        return match *self {
+            CharacterClass::Lower => c.is_ascii_lowercase(),
+            CharacterClass::Upper => c.is_ascii_uppercase(),
+            CharacterClass::Digit => c.is_ascii_digit(),
+            CharacterClass::Symbol => c.is_ascii_punctuation() || c == ' ',
+            CharacterClass::Custom(ref chars) => chars.contains(&c),
+        };
+    }
+}
+
+impl fmt::Display for CharacterClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match *self {
+            CharacterClass::Lower => write!(f, "a lowercase letter"),
+            CharacterClass::Upper => write!(f, "an uppercase letter"),
+            CharacterClass::Digit => write!(f, "a digit"),
+            CharacterClass::Symbol => write!(f, "a symbol"),
+            CharacterClass::Custom(ref chars) => write!(f, "one of '{}'", chars.iter().collect::<String>()),
+        };
+    }
+}
+
+/// The rules a password must follow. The default policy allows any password.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PasswordPolicy {
+    /// The minimum length of the password in characters
+    pub min_length: Option<usize>,
+    /// The maximum length of the password in characters
+    pub max_length: Option<usize>,
+    /// The password must contain at least one character from each class
+    pub required: Vec<CharacterClass>,
+    /// The password must not contain any of these characters
+    pub forbidden: Vec<char>,
+    /// If not empty, the password may only contain characters from these classes
+    pub allowed: Vec<CharacterClass>,
+    /// The most times the same character can appear in a row
+    pub max_consecutive: Option<usize>,
+}
+
+/// The ways a password can break a policy
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolicyViolation {
+    /// The password is shorter than the minimum length
+    TooShort {
+        /// The length of the password
+        length: usize,
+        /// The minimum length of the policy
+        min_length: usize,
+    },
+    /// The password is longer than the maximum length
+    TooLong {
+        /// The length of the password
+        length: usize,
+        /// The maximum length of the policy
+        max_length: usize,
+    },
+    /// The password does not contain a character from a required class
+    MissingClass(CharacterClass),
+    /// The password contains a forbidden character
+    ForbiddenCharacter(char),
+    /// The same character appears too many times in a row
+    TooManyConsecutive {
+        /// The repeated character
+        character: char,
+        /// The number of times it appears in a row
+        count: usize,
+    },
+}
+
+impl fmt::Display for PolicyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match *self {
+            PolicyViolation::TooShort { length, min_length } => {
+                write!(f, "the password is {} characters long but must be at least {}", length, min_length)
+            },
+            PolicyViolation::TooLong { length, max_length } => {
+                write!(f, "the password is {} characters long but must be at most {}", length, max_length)
+            },
+            PolicyViolation::MissingClass(ref class) => write!(f, "the password must contain {}", class),
+            PolicyViolation::ForbiddenCharacter(c) => write!(f, "the password must not contain '{}'", c),
+            PolicyViolation::TooManyConsecutive { character, count } => {
+                write!(f, "'{}' appears {} times in a row", character, count)
+            },
+        };
+    }
+}
+
+impl Error for PolicyViolation {}
+
+/// The reasons a password satisfying a policy could not be generated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolicyError {
+    /// The configuration cannot be used with the word list
+    InvalidConfiguration(ConfigError),
+    /// The configuration cannot generate a password which satisfies the policy. Contains the
+    /// reason the last attempt broke the policy
+    Unsatisfiable(PolicyViolation),
+}
+
+impl fmt::Display for PolicyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match *self {
+            PolicyError::InvalidConfiguration(ref err) => write!(f, "invalid configuration: {}", err),
+            PolicyError::Unsatisfiable(ref violation) => write!(f, "the policy cannot be satisfied: {}", violation),
+        };
+    }
+}
+
+impl Error for PolicyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        return match *self {
+            PolicyError::InvalidConfiguration(ref err) => Some(err),
+            PolicyError::Unsatisfiable(ref violation) => Some(violation),
+        };
+    }
+}
+
+impl From<ConfigError> for PolicyError {
+    fn from(err: ConfigError) -> PolicyError {
+        return PolicyError::InvalidConfiguration(err);
+    }
+}
+
+impl PasswordPolicy {
+    /// Checks that the password follows the policy, returning the first rule it breaks.
+    pub fn check(&self, password: &str) -> Result<(), PolicyViolation> {
+        let length = password.chars().count();
+        if let Some(min_length) = self.min_length {
+            if length < min_length {
+                return Err(PolicyViolation::TooShort { length, min_length });
+            }
+        }
+        if let Some(max_length) = self.max_length {
+            if length > max_length {
+                return Err(PolicyViolation::TooLong { length, max_length });
+            }
+        }
+
+        for class in &self.required {
+            if !password.chars().any(|a| class.contains(a)) {
+                return Err(PolicyViolation::MissingClass(class.clone()));
+            }
+        }
+
+        if let Some(c) = password.chars().find(|a| !self.permits(*a)) {
+            return Err(PolicyViolation::ForbiddenCharacter(c));
+        }
+
+        if let Some(max_consecutive) = self.max_consecutive {
+            let mut previous = None;
+            let mut count = 0;
+            for c in password.chars() {
+                count = if previous == Some(c) { count + 1 } else { 1 };
+                previous = Some(c);
+                if count > max_consecutive {
+                    return Err(PolicyViolation::TooManyConsecutive { character: c, count });
+                }
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// Whether the character is allowed and not forbidden
+    pub fn permits(&self, c: char) -> bool {
+        return !self.forbidden.contains(&c) && (self.allowed.is_empty() || self.allowed.iter().any(|a| a.contains(c)));
+    }
+
+    /// Adapts a configuration so its passwords are more likely to follow the policy:
+    ///
+    /// * Characters which are not permitted are removed from the seperators and padding
+    ///   characters, unless none of them is permitted
+    /// * Words are made uppercase if lowercase letters are not permitted, and lowercase if
+    ///   uppercase letters are not permitted
+    /// * Padding digits and leetspeak are removed if digits are not permitted
+    /// * The first letter of every word is capitalised if an uppercase letter is required and no
+    ///   transformation adds one
+    /// * A padding digit is added if a digit is required and the configuration has no padding
+    ///   digits. Leetspeak is not relied on, as a word may not have any letters to substitute
+    /// * Adaptive padding is shortened to the maximum length
+    /// * Fixed padding is shortened to the maximum number of consecutive characters, as it repeats
+    ///   one padding character
+    pub fn adapt_configuration(&self, config: &Configuration) -> Configuration {
+        let mut config = config.clone();
+
+        let allowed = |chars: &[char]| -> Vec<char> { chars.iter().cloned().filter(|a| self.permits(*a)).collect() };
+        let seperators = allowed(&config.seperator.seperators);
+        if !seperators.is_empty() {
+            config.seperator.seperators = seperators;
+        }
+        let padding_chars = allowed(&config.padding_symbols.padding_chars);
+        if !padding_chars.is_empty() {
+            config.padding_symbols.padding_chars = padding_chars;
+        }
+
+        let permits_lower = ('a'..='z').all(|a| self.permits(a));
+        let permits_upper = ('A'..='Z').all(|a| self.permits(a));
+        if !permits_lower && permits_upper {
+            config.words.transformations.push(WordTransformations::UpperCase);
+        } else if permits_lower && !permits_upper {
+            config.words.transformations.push(WordTransformations::LowerCase);
+        }
+
+        if !('0'..='9').all(|a| self.permits(a)) {
+            config.padding_digits.num_before = 0;
+            config.padding_digits.num_after = 0;
+            config.words.transformations.retain(|a| !matches!(*a, WordTransformations::Leetspeak | WordTransformations::RandomLeetspeak));
+        }
+
+        if self.required.contains(&CharacterClass::Upper) {
+            let adds_upper = config.words.transformations.iter().any(|a| {
+                matches!(*a, WordTransformations::CapitaliseFirst | WordTransformations::CapitaliseNonFirst
+                    | WordTransformations::UpperCase | WordTransformations::AlternatingLowerUpper
+                    | WordTransformations::RandomLowerUpper | WordTransformations::RandomCharacterCase)
+            });
+            if !adds_upper {
+                config.words.transformations.push(WordTransformations::CapitaliseFirst);
+            }
+        }
+
+        let has_padding_digits = config.padding_digits.num_before > 0 || config.padding_digits.num_after > 0;
+        if self.required.contains(&CharacterClass::Digit) && !has_padding_digits {
+            config.padding_digits.num_after = 1;
+        }
+
+        if let Some(max_length) = self.max_length {
+            if let PaddingTypes::Adaptive(ref mut pad_length) = config.padding_symbols.padding_type {
+                if *pad_length as usize > max_length {
+                    *pad_length = max_length as u32;
+                }
+            }
+        }
+
+        if let Some(max_consecutive) = self.max_consecutive {
+            if let PaddingTypes::Fixed(ref mut num_pad_before, ref mut num_pad_after) = config.padding_symbols.padding_type {
+                let max_consecutive = max_consecutive.min(u8::MAX as usize) as u8;
+                *num_pad_before = (*num_pad_before).min(max_consecutive);
+                *num_pad_after = (*num_pad_after).min(max_consecutive);
+            }
+        }
+
+        return config;
+    }
+}
+
+/// Generates a password which follows the policy. The configuration is adapted to the policy
+/// with `PasswordPolicy::adapt_configuration`, then passwords are generated until one follows the
+/// policy.
+///
+/// Returns `PolicyError::Unsatisfiable` if the configuration cannot make a password of the right
+/// length, or if none of 1000 passwords follows the policy.
+pub fn try_generate_password_with_policy<A: WordList>(config: &Configuration, word_list: &A, policy: &PasswordPolicy) -> Result<String, PolicyError> {
+    return try_generate_password_with_policy_with_rng(config, word_list, policy, &mut thread_rng());
+}
+
+/// Generates a password which follows the policy with the given random number generator. See
+/// `try_generate_password_with_policy`.
+pub fn try_generate_password_with_policy_with_rng<A: WordList, R: Rng>(config: &Configuration, word_list: &A, policy: &PasswordPolicy, rgen: &mut R) -> Result<String, PolicyError> {
+    let config = policy.adapt_configuration(config);
+    // Give up straight away if no password can have the right length
+    let entropy = calculate_entropy(&config, word_list)?;
+    if let Some(min_length) = policy.min_length {
+        if entropy.max_length < min_length {
+            return Err(PolicyError::Unsatisfiable(PolicyViolation::TooShort { length: entropy.max_length, min_length }));
+        }
+    }
+    if let Some(max_length) = policy.max_length {
+        if entropy.min_length > max_length {
+            return Err(PolicyError::Unsatisfiable(PolicyViolation::TooLong { length: entropy.min_length, max_length }));
+        }
+    }
+
+    let mut last_violation = None;
+    for _ in 0..MAX_ATTEMPTS {
+        // The configuration has already been validated, so this cannot panic
+        let mut password = generate_password_with_rng(&config, word_list, rgen);
+        match policy.check(&password) {
+            Ok(()) => return Ok(password),
+            Err(violation) => last_violation = Some(violation),
+        }
+        wipe(&mut password);
+    }
+    return Err(PolicyError::Unsatisfiable(last_violation.expect("at least one password was generated")));
+}
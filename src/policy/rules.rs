@@ -0,0 +1,233 @@
+//! Parsing of the `passwordrules` attribute proposed by WHATWG and supported by Safari and
+//! Chrome, which lets a website declare the rules its passwords must follow.
+//!
+//! A rule list such as `minlength: 20; required: lower; required: upper; max-consecutive: 2`
+//! becomes a `PasswordPolicy`, which can then be used with `try_generate_password_with_policy`.
+//! The rules must be the decoded value of the attribute, so `&quot;` in the HTML source must
+//! already be `"`.
+//!
+//! # Example
+//!
+//! ```
+//! use xkcd_pass::policy::{CharacterClass, PasswordPolicy};
+//!
+//! let policy = PasswordPolicy::from_password_rules("minlength: 20; required: lower; required: upper; allowed: [-().&@?'#,/\"+]; max-consecutive: 2").unwrap();
+//! assert_eq!(policy.min_length, Some(20));
+//! assert_eq!(policy.required, vec![CharacterClass::Lower, CharacterClass::Upper]);
+//! assert_eq!(policy.max_consecutive, Some(2));
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+use super::{CharacterClass, PasswordPolicy};
+
+/// The reasons a `passwordrules` value could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PasswordRulesError {
+    /// A rule has no `:` between its name and its value. Contains the rule
+    MissingColon(String),
+    /// A character class is not one of `upper`, `lower`, `digit`, `special`, `ascii-printable`,
+    /// `unicode` or a custom class in brackets. Contains the class
+    UnknownClass(String),
+    /// A custom character class has no closing `]`
+    UnterminatedCustomClass,
+    /// The value of `minlength`, `maxlength` or `max-consecutive` is not a number
+    InvalidNumber {
+        /// The name of the rule
+        rule: String,
+        /// The value which is not a number
+        value: String,
+    },
+}
+
+impl fmt::Display for PasswordRulesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match *self {
+            PasswordRulesError::MissingColon(ref rule) => write!(f, "the rule '{}' has no ':'", rule),
+            PasswordRulesError::UnknownClass(ref class) => write!(f, "unknown character class '{}'", class),
+            PasswordRulesError::UnterminatedCustomClass => write!(f, "a custom character class has no closing ']'"),
+            PasswordRulesError::InvalidNumber { ref rule, ref value } => write!(f, "the value '{}' of '{}' is not a number", value, rule),
+        };
+    }
+}
+
+impl Error for PasswordRulesError {}
+
+/// A character class as written in the rules. `unicode` allows any character, so it cannot be a
+/// `CharacterClass`
+enum RuleClass {
+    Class(CharacterClass),
+    Unicode,
+}
+
+impl PasswordPolicy {
+    /// Parses the value of a `passwordrules` attribute into a policy.
+    ///
+    /// * `minlength` and `maxlength` set the length limits. If a rule is repeated the strictest
+    ///   value is used
+    /// * Each `required` rule needs at least one character from any of its classes
+    /// * The password may only contain characters from the `allowed` and `required` classes.
+    ///   Without either rule, any printable ASCII character is allowed
+    /// * `max-consecutive` limits how many times a character can appear in a row
+    ///
+    /// Rule names are case insensitive and unknown rules are ignored, as they are by browsers.
+    pub fn from_password_rules(rules: &str) -> Result<PasswordPolicy, PasswordRulesError> {
+        let mut policy = PasswordPolicy::default();
+        let mut allowed = Vec::new();
+        let mut allows_unicode = false;
+        let mut has_class_rule = false;
+
+        for rule in split_rules(rules)? {
+            let rule = rule.trim();
+            if rule.is_empty() {
+                continue;
+            }
+            let colon = rule.find(':').ok_or_else(|| PasswordRulesError::MissingColon(rule.to_string()))?;
+            let name = rule[..colon].trim().to_lowercase();
+            let value = rule[colon + 1..].trim();
+
+            match name.as_str() {
+                "minlength" => {
+                    let min_length = parse_number(&name, value)?;
+                    policy.min_length = Some(policy.min_length.map_or(min_length, |a| a.max(min_length)));
+                },
+                "maxlength" => {
+                    let max_length = parse_number(&name, value)?;
+                    policy.max_length = Some(policy.max_length.map_or(max_length, |a| a.min(max_length)));
+                },
+                "max-consecutive" => {
+                    let max_consecutive = parse_number(&name, value)?;
+                    policy.max_consecutive = Some(policy.max_consecutive.map_or(max_consecutive, |a| a.min(max_consecutive)));
+                },
+                "required" | "allowed" => {
+                    has_class_rule = true;
+                    let classes = parse_classes(value)?;
+                    let mut named = Vec::new();
+                    let mut is_unicode = false;
+                    for class in classes {
+                        match class {
+                            RuleClass::Class(class) => named.push(class),
+                            RuleClass::Unicode => is_unicode = true,
+                        }
+                    }
+                    allows_unicode |= is_unicode;
+
+                    // Any character satisfies a required class which includes unicode
+                    if name == "required" && !is_unicode && !named.is_empty() {
+                        policy.required.push(combine_classes(&named));
+                    }
+                    for class in named {
+                        if !allowed.contains(&class) {
+                            allowed.push(class);
+                        }
+                    }
+                },
+                _ => {},
+            }
+        }
+
+        if !has_class_rule {
+            allowed = ascii_printable();
+        }
+        if !allows_unicode {
+            policy.allowed = allowed;
+        }
+
+        return Ok(policy);
+    }
+}
+
+/// Splits the rules at the `;` which are not inside a custom character class
+fn split_rules(rules: &str) -> Result<Vec<&str>, PasswordRulesError> {
+    let mut split = Vec::new();
+    let mut start = 0;
+    let mut chars = rules.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            ';' => {
+                split.push(&rules[start..i]);
+                start = i + 1;
+            },
+            '[' => {
+                custom_class_chars(&mut chars)?;
+            },
+            _ => {},
+        }
+    }
+    split.push(&rules[start..]);
+    return Ok(split);
+}
+
+/// Reads the characters of a custom character class up to its closing `]`, which has already
+/// been opened. A `]` straight after the `[` is part of the class
+fn custom_class_chars<I: Iterator<Item = (usize, char)>>(chars: &mut I) -> Result<Vec<char>, PasswordRulesError> {
+    let mut class = Vec::new();
+    for (_, c) in chars {
+        if c == ']' && !class.is_empty() {
+            return Ok(class);
+        }
+        if !class.contains(&c) {
+            class.push(c);
+        }
+    }
+    return Err(PasswordRulesError::UnterminatedCustomClass);
+}
+
+/// Parses the comma separated character classes of a `required` or `allowed` rule
+fn parse_classes(value: &str) -> Result<Vec<RuleClass>, PasswordRulesError> {
+    let mut classes = Vec::new();
+    let mut name = String::new();
+    let mut chars = value.char_indices();
+    while let Some((_, c)) = chars.next() {
+        match c {
+            '[' => classes.push(RuleClass::Class(CharacterClass::Custom(custom_class_chars(&mut chars)?))),
+            ',' => {
+                classes.extend(parse_named_class(&name)?);
+                name.clear();
+            },
+            _ => name.push(c),
+        }
+    }
+    classes.extend(parse_named_class(&name)?);
+    return Ok(classes);
+}
+
+/// Parses a named character class. `ascii-printable` is made of several classes
+fn parse_named_class(name: &str) -> Result<Vec<RuleClass>, PasswordRulesError> {
+    let name = name.trim();
+    return match name.to_lowercase().as_str() {
+        "" => Ok(Vec::new()),
+        "lower" => Ok(vec![RuleClass::Class(CharacterClass::Lower)]),
+        "upper" => Ok(vec![RuleClass::Class(CharacterClass::Upper)]),
+        "digit" => Ok(vec![RuleClass::Class(CharacterClass::Digit)]),
+        "special" => Ok(vec![RuleClass::Class(CharacterClass::Symbol)]),
+        "ascii-printable" => Ok(ascii_printable().into_iter().map(RuleClass::Class).collect()),
+        "unicode" => Ok(vec![RuleClass::Unicode]),
+        _ => Err(PasswordRulesError::UnknownClass(name.to_string())),
+    };
+}
+
+fn parse_number(rule: &str, value: &str) -> Result<usize, PasswordRulesError> {
+    return value.parse().map_err(|_| PasswordRulesError::InvalidNumber { rule: rule.to_string(), value: value.to_string() });
+}
+
+/// Combines the classes of a `required` rule into one class which any of their characters
+/// satisfies
+fn combine_classes(classes: &[CharacterClass]) -> CharacterClass {
+    if classes.len() == 1 {
+        return classes[0].clone();
+    }
+    let mut chars: Vec<char> = (' '..='~').filter(|a| classes.iter().any(|b| b.contains(*a))).collect();
+    for class in classes {
+        if let CharacterClass::Custom(ref custom) = *class {
+            chars.extend(custom.iter().filter(|a| !a.is_ascii()));
+        }
+    }
+    return CharacterClass::Custom(chars);
+}
+
+/// The classes which together make every printable ASCII character
+fn ascii_printable() -> Vec<CharacterClass> {
+    return vec![CharacterClass::Lower, CharacterClass::Upper, CharacterClass::Digit, CharacterClass::Symbol];
+}
@@ -0,0 +1,112 @@
+use rand::{SeedableRng, StdRng};
+use super::{try_generate_password_with_policy_with_rng, CharacterClass, PasswordPolicy, PasswordRulesError, PolicyError, PolicyViolation};
+use super::super::configuration::{Configuration, PaddingTypes, WordTransformations};
+use super::super::word_list::SimpleEnglish;
+
+#[test]
+pub fn test_check_policy() {
+    let policy = PasswordPolicy {
+        min_length: Some(8),
+        max_length: Some(16),
+        required: vec![CharacterClass::Upper, CharacterClass::Digit],
+        forbidden: vec!['&'],
+        allowed: Vec::new(),
+        max_consecutive: Some(2),
+    };
+
+    assert_eq!(policy.check("Horse-staple-42"), Ok(()));
+    assert_eq!(policy.check("Horse-42"), Ok(()));
+    assert_eq!(policy.check("Horse4"), Err(PolicyViolation::TooShort { length: 6, min_length: 8 }));
+    assert_eq!(policy.check("Horse-staple-battery-42"), Err(PolicyViolation::TooLong { length: 23, max_length: 16 }));
+    assert_eq!(policy.check("horse-staple-42"), Err(PolicyViolation::MissingClass(CharacterClass::Upper)));
+    assert_eq!(policy.check("Horse&staple-42"), Err(PolicyViolation::ForbiddenCharacter('&')));
+    assert_eq!(policy.check("Horse---stap-42"), Err(PolicyViolation::TooManyConsecutive { character: '-', count: 3 }));
+}
+
+#[test]
+pub fn test_adapt_configuration() {
+    let policy = PasswordPolicy {
+        required: vec![CharacterClass::Upper, CharacterClass::Digit],
+        forbidden: vec!['-'],
+        ..PasswordPolicy::default()
+    };
+
+    let mut config = Configuration::xkcd();
+    config.words.transformations = vec![WordTransformations::LowerCase];
+    config.seperator.seperators = vec!['-', '.'];
+
+    let adapted = policy.adapt_configuration(&config);
+    assert_eq!(adapted.words.transformations, vec![WordTransformations::LowerCase, WordTransformations::CapitaliseFirst]);
+    assert_eq!(adapted.padding_digits.num_after, 1);
+    assert_eq!(adapted.seperator.seperators, vec!['.']);
+
+    // Leetspeak does not always add a digit, so a padding digit is still added
+    config.words.transformations = vec![WordTransformations::RandomLeetspeak, WordTransformations::Leetspeak];
+    assert_eq!(policy.adapt_configuration(&config).padding_digits.num_after, 1);
+
+    // The default configuration pads with two of the same character at each end
+    let policy = PasswordPolicy { max_consecutive: Some(1), ..PasswordPolicy::default() };
+    let adapted = policy.adapt_configuration(&Configuration::default());
+    assert_eq!(adapted.padding_symbols.padding_type, PaddingTypes::Fixed(1, 1));
+}
+
+#[test]
+pub fn test_generate_with_policy() {
+    let word_list = SimpleEnglish::new();
+    let seed: &[_] = &[5];
+    let policy = PasswordPolicy {
+        max_length: Some(20),
+        required: vec![CharacterClass::Upper, CharacterClass::Digit, CharacterClass::Symbol],
+        forbidden: vec!['&'],
+        max_consecutive: Some(2),
+        ..PasswordPolicy::default()
+    };
+
+    let mut config = Configuration::xkcd();
+    config.words.num_words = 3;
+    let password = try_generate_password_with_policy_with_rng(&config, &word_list, &policy, &mut StdRng::from_seed(seed)).unwrap();
+    assert_eq!(policy.check(&password), Ok(()));
+
+    // The padding of the default configuration is shortened so it can be satisfied
+    let policy = PasswordPolicy { max_consecutive: Some(1), ..PasswordPolicy::default() };
+    let password = try_generate_password_with_policy_with_rng(&Configuration::default(), &word_list, &policy, &mut StdRng::from_seed(seed)).unwrap();
+    assert_eq!(policy.check(&password), Ok(()));
+
+    // Four words of at least 4 letters can never fit in 16 characters
+    let policy = PasswordPolicy { max_length: Some(16), ..PasswordPolicy::default() };
+    let result = try_generate_password_with_policy_with_rng(&Configuration::xkcd(), &word_list, &policy, &mut StdRng::from_seed(seed));
+    assert_eq!(result, Err(PolicyError::Unsatisfiable(PolicyViolation::TooLong { length: 19, max_length: 16 })));
+}
+
+#[test]
+pub fn test_password_rules() {
+    let policy = PasswordPolicy::from_password_rules("minlength: 20; maxlength: 40; MinLength: 12; required: lower; required: upper, digit; allowed: [-;]; max-consecutive: 2; unknown: 1").unwrap();
+    assert_eq!(policy.min_length, Some(20));
+    assert_eq!(policy.max_length, Some(40));
+    assert_eq!(policy.max_consecutive, Some(2));
+    assert_eq!(policy.required[0], CharacterClass::Lower);
+    assert!(policy.required[1].contains('Q') && policy.required[1].contains('7') && !policy.required[1].contains('q'));
+    assert!(policy.permits(';') && policy.permits('a') && !policy.permits('!'));
+
+    // Without any classes only printable ASCII is allowed, and unicode allows everything
+    assert!(!PasswordPolicy::from_password_rules("minlength: 8").unwrap().permits('é'));
+    assert!(PasswordPolicy::from_password_rules("allowed: unicode").unwrap().allowed.is_empty());
+
+    assert_eq!(PasswordPolicy::from_password_rules("required: emoji"), Err(PasswordRulesError::UnknownClass("emoji".to_string())));
+    assert_eq!(PasswordPolicy::from_password_rules("allowed: [-;"), Err(PasswordRulesError::UnterminatedCustomClass));
+    assert_eq!(PasswordPolicy::from_password_rules("minlength 8"), Err(PasswordRulesError::MissingColon("minlength 8".to_string())));
+}
+
+#[test]
+pub fn test_generate_with_password_rules() {
+    let word_list = SimpleEnglish::new();
+    let seed: &[_] = &[7];
+    let policy = PasswordPolicy::from_password_rules("minlength: 20; required: upper; required: digit; allowed: lower, [-]; max-consecutive: 2").unwrap();
+
+    let adapted = policy.adapt_configuration(&Configuration::default());
+    assert_eq!(adapted.seperator.seperators, vec!['-']);
+    assert_eq!(adapted.padding_symbols.padding_chars, vec!['-']);
+
+    let password = try_generate_password_with_policy_with_rng(&Configuration::default(), &word_list, &policy, &mut StdRng::from_seed(seed)).unwrap();
+    assert_eq!(policy.check(&password), Ok(()));
+}
@@ -0,0 +1,103 @@
+//! Passwords which are wiped from memory when they are dropped, for programs that keep
+//! passwords in memory such as credential vaults.
+//!
+//! `try_generate_secret_password` returns a `SecretString`, which overwrites its memory when it
+//! is dropped and hides its contents when formatted with `Debug`. The generator builds the
+//! password in a single buffer which is never moved, and wipes the words and other intermediate
+//! strings once the password is built.
+//!
+//! With the `zeroize` feature the rest of the crate also wipes the passwords it throws away, such
+//! as duplicates in a batch or passwords rejected by a policy, and the derived keys. This is a best
+//! effort: copies made by other crates, such as zxcvbn when checking the strength of a password or
+//! the state of the random number generator, are not wiped.
+//!
+//! Requires the `zeroize` cargo feature.
+//!
+//! # Example
+//!
+//! ```
+//! use xkcd_pass::{Configuration, SimpleEnglish};
+//! use xkcd_pass::secret::try_generate_secret_password;
+//!
+//! let password = try_generate_secret_password(&Configuration::default(), &SimpleEnglish::new()).unwrap();
+//! assert_eq!(format!("{:?}", password), "SecretString([REDACTED])");
+//! println!("Password: {}", password.expose_secret());
+//! ```
+
+use std::fmt;
+use rand::{Rng, thread_rng};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use super::configuration::Configuration;
+use super::generator::{try_generate_password_with_rng, GenerateError};
+use super::word_list::WordList;
+
+#[cfg(test)]
+mod tests;
+
+/// A string which is wiped from memory when it is dropped. The contents can only be read with
+/// `expose_secret`, and are hidden when formatted with `Debug`.
+pub struct SecretString {
+    secret: String,
+}
+
+impl SecretString {
+    /// Takes ownership of the string without copying it
+    pub fn new(secret: String) -> SecretString {
+        return SecretString { secret };
+    }
+
+    /// The secret contents. Any copies made of them are not wiped
+    pub fn expose_secret(&self) -> &str {
+        return &self.secret;
+    }
+
+    /// The length of the secret in bytes
+    pub fn len(&self) -> usize {
+        return self.secret.len();
+    }
+
+    /// Whether the secret is empty
+    pub fn is_empty(&self) -> bool {
+        return self.secret.is_empty();
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(secret: String) -> SecretString {
+        return SecretString::new(secret);
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "SecretString([REDACTED])");
+    }
+}
+
+impl Zeroize for SecretString {
+    fn zeroize(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+impl Drop for SecretString {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecretString {}
+
+/// Generates a password which is wiped from memory when it is dropped, returning an error if the
+/// configuration is invalid.
+pub fn try_generate_secret_password<A: WordList>(config: &Configuration, word_list: &A) -> Result<SecretString, GenerateError> {
+    return try_generate_secret_password_with_rng(config, word_list, &mut thread_rng());
+}
+
+/// Generates a password which is wiped from memory when it is dropped with the given random
+/// number generator, returning an error if the configuration is invalid. The same seed generates
+/// the same password as `try_generate_password_with_rng`.
+pub fn try_generate_secret_password_with_rng<A: WordList, R: Rng>(config: &Configuration, word_list: &A, rgen: &mut R) -> Result<SecretString, GenerateError> {
+    return try_generate_password_with_rng(config, word_list, rgen).map(SecretString::new);
+}
@@ -0,0 +1,35 @@
+use rand::{SeedableRng, StdRng};
+use super::{try_generate_secret_password_with_rng, SecretString};
+use super::super::configuration::{Configuration, WordTransformations};
+use super::super::generator::{try_generate_password_with_rng, wipe};
+use super::super::word_list::SimpleEnglish;
+
+#[test]
+pub fn test_secret_string() {
+    let secret = SecretString::from("correct horse".to_string());
+    assert_eq!(secret.expose_secret(), "correct horse");
+    assert_eq!(secret.len(), 13);
+    assert_eq!(format!("{:?}", secret), "SecretString([REDACTED])");
+}
+
+#[test]
+pub fn test_wipe() {
+    let mut word = "staple".to_string();
+    wipe(&mut word);
+    assert!(word.is_empty());
+}
+
+#[test]
+pub fn test_same_as_plain_password() {
+    let word_list = SimpleEnglish::new();
+    let seed: &[_] = &[3, 1, 4];
+    let mut config = Configuration::default();
+    config.words.transformations = vec![
+        WordTransformations::RandomCharacterCase,
+        WordTransformations::RandomLeetspeak,
+    ];
+
+    let plain = try_generate_password_with_rng(&config, &word_list, &mut StdRng::from_seed(seed)).unwrap();
+    let secret = try_generate_secret_password_with_rng(&config, &word_list, &mut StdRng::from_seed(seed)).unwrap();
+    assert_eq!(secret.expose_secret(), plain);
+}
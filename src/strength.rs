@@ -0,0 +1,167 @@
+//! Estimates the strength of a concrete password against dictionary, pattern and keyboard
+//! attacks with zxcvbn.
+//!
+//! Unlike `calculate_entropy`, which measures a configuration, this scores a single password, so
+//! it can also be used for passwords chosen by users. The word list a password was generated
+//! from can be added to the dictionaries zxcvbn checks, which assumes the attacker knows it.
+//!
+//! Requires the `strength` cargo feature.
+//!
+//! # Example
+//!
+//! ```
+//! use xkcd_pass::SimpleEnglish;
+//! use xkcd_pass::strength::{estimate_strength, estimate_strength_with_word_list};
+//!
+//! assert_eq!(estimate_strength("password").score, 0);
+//! let strength = estimate_strength_with_word_list("staple-horse-battery-correct", &SimpleEnglish::new());
+//! println!("Score: {} / 4, {} guesses", strength.score, strength.guesses);
+//! ```
+
+use rand::{Rng, thread_rng};
+use zxcvbn::zxcvbn;
+use zxcvbn::time_estimates::CrackTimeSeconds;
+
+use super::configuration::Configuration;
+use super::generator::{build_password, wipe, GenerateError};
+use super::word_list::WordList;
+
+#[cfg(test)]
+mod tests;
+
+/// The highest score a password can get
+pub const MAX_SCORE: u8 = 4;
+
+/// The number of passwords generated before `try_generate_strong_password` gives up
+const MAX_ATTEMPTS: usize = 100;
+
+/// The estimated strength of a password
+#[derive(Debug, Clone, PartialEq)]
+pub struct Strength {
+    /// The estimated number of guesses needed to crack the password
+    pub guesses: u64,
+    /// The base 10 logarithm of `guesses`
+    pub guesses_log10: f64,
+    /// The overall strength from 0 to 4. Any score less than 3 should be considered too weak
+    pub score: u8,
+    /// The seconds needed to crack the password online with throttling, at 100 guesses an hour
+    pub online_throttled_seconds: f64,
+    /// The seconds needed to crack the password online without throttling, at 10 guesses a
+    /// second
+    pub online_unthrottled_seconds: f64,
+    /// The seconds needed to crack the password offline with a slow hash, at 10,000 guesses a
+    /// second
+    pub offline_slow_hash_seconds: f64,
+    /// The seconds needed to crack the password offline with a fast hash, at 10 billion guesses
+    /// a second
+    pub offline_fast_hash_seconds: f64,
+    /// Why the password is weak, if it is
+    pub warning: Option<String>,
+    /// How to make the password stronger
+    pub suggestions: Vec<String>,
+}
+
+/// Estimates the strength of a password. An empty password gets a score of 0 and needs a single
+/// guess.
+pub fn estimate_strength(password: &str) -> Strength {
+    return estimate(password, &[]);
+}
+
+/// Estimates the strength of a password, adding the words in the word list to the dictionaries
+/// the password is checked against.
+pub fn estimate_strength_with_word_list<A: WordList>(password: &str, word_list: &A) -> Strength {
+    let words: Vec<&str> = word_list.get_words(0, u8::MAX).iter().map(|a| a.as_str()).collect();
+    return estimate(password, &words);
+}
+
+/// Generates passwords until one has at least the minimum score. The word list is added to the
+/// dictionaries the passwords are checked against.
+pub fn try_generate_strong_password<A: WordList>(config: &Configuration, word_list: &A, min_score: u8) -> Result<String, GenerateError> {
+    return try_generate_strong_password_with_rng(config, word_list, min_score, &mut thread_rng());
+}
+
+/// Generates passwords with the given random number generator until one has at least the
+/// minimum score. Returns `GenerateError::InvalidMinScore` straight away if the minimum score is
+/// higher than `MAX_SCORE`, and `GenerateError::TooWeak` if no password is strong enough after
+/// 100 attempts.
+///
+/// ```
+/// extern crate rand;
+/// extern crate xkcd_pass;
+///
+/// use rand::{SeedableRng, StdRng};
+/// use xkcd_pass::{Configuration, SimpleEnglish};
+/// use xkcd_pass::strength::{estimate_strength_with_word_list, try_generate_strong_password_with_rng};
+///
+/// # fn main() {
+/// let word_list = SimpleEnglish::new();
+/// let seed: &[_] = &[1, 2, 3, 4];
+/// let password = try_generate_strong_password_with_rng(&Configuration::default(), &word_list, 4, &mut StdRng::from_seed(seed)).unwrap();
+/// assert_eq!(estimate_strength_with_word_list(&password, &word_list).score, 4);
+/// # }
+/// ```
+pub fn try_generate_strong_password_with_rng<A: WordList, R: Rng>(config: &Configuration, word_list: &A, min_score: u8, rgen: &mut R) -> Result<String, GenerateError> {
+    if min_score > MAX_SCORE {
+        return Err(GenerateError::InvalidMinScore(min_score));
+    }
+
+    config.validate(word_list)?;
+
+    let words: Vec<&str> = word_list.get_words(0, u8::MAX).iter().map(|a| a.as_str()).collect();
+    for _ in 0..MAX_ATTEMPTS {
+        let mut password = build_password(config, word_list, rgen);
+        if estimate(&password, &words).score >= min_score {
+            return Ok(password);
+        }
+        wipe(&mut password);
+    }
+    return Err(GenerateError::TooWeak { min_score, attempts: MAX_ATTEMPTS });
+}
+
+/// Runs zxcvbn with the extra dictionary words
+fn estimate(password: &str, words: &[&str]) -> Strength {
+    let entropy = match zxcvbn(password, words) {
+        Ok(entropy) => entropy,
+        // zxcvbn cannot score an empty password, which is found with the first guess
+        Err(_) => {
+            return Strength {
+                guesses: 1,
+                guesses_log10: 0.0,
+                score: 0,
+                online_throttled_seconds: 0.0,
+                online_unthrottled_seconds: 0.0,
+                offline_slow_hash_seconds: 0.0,
+                offline_fast_hash_seconds: 0.0,
+                warning: None,
+                suggestions: Vec::new(),
+            };
+        },
+    };
+
+    let crack_times = entropy.crack_times();
+    let (warning, suggestions) = match *entropy.feedback() {
+        Some(ref feedback) => {
+            (feedback.warning().map(|a| a.to_string()), feedback.suggestions().iter().map(|a| a.to_string()).collect())
+        },
+        None => (None, Vec::new()),
+    };
+
+    return Strength {
+        guesses: entropy.guesses(),
+        guesses_log10: entropy.guesses_log10(),
+        score: entropy.score(),
+        online_throttled_seconds: seconds(crack_times.online_throttling_100_per_hour()),
+        online_unthrottled_seconds: seconds(crack_times.online_no_throttling_10_per_second()),
+        offline_slow_hash_seconds: seconds(crack_times.offline_slow_hashing_1e4_per_second()),
+        offline_fast_hash_seconds: seconds(crack_times.offline_fast_hashing_1e10_per_second()),
+        warning,
+        suggestions,
+    };
+}
+
+fn seconds(crack_time: CrackTimeSeconds) -> f64 {
+    return match crack_time {
+        CrackTimeSeconds::Integer(seconds) => seconds as f64,
+        CrackTimeSeconds::Float(seconds) => seconds,
+    };
+}
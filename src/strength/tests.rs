@@ -0,0 +1,47 @@
+use rand::{SeedableRng, StdRng};
+use super::{estimate_strength, estimate_strength_with_word_list, try_generate_strong_password_with_rng, MAX_SCORE};
+use super::super::configuration::Configuration;
+use super::super::generator::GenerateError;
+use super::super::word_list::{FileWordList, FileWordListOptions};
+
+#[test]
+pub fn test_estimate_strength() {
+    let weak = estimate_strength("qwerty123");
+    assert!(weak.score < 2);
+    assert!(weak.warning.is_some());
+
+    let empty = estimate_strength("");
+    assert_eq!(empty.score, 0);
+    assert_eq!(empty.guesses, 1);
+}
+
+#[test]
+pub fn test_word_list_lowers_strength() {
+    let word_list = FileWordList::from_reader("zorbulent\nquimflax".as_bytes(), &FileWordListOptions::default()).unwrap();
+
+    let unknown = estimate_strength("zorbulentquimflax");
+    let known = estimate_strength_with_word_list("zorbulentquimflax", &word_list);
+    assert!(known.guesses < unknown.guesses);
+}
+
+#[test]
+pub fn test_strong_password_is_not_possible() {
+    // A single word from a two word list is never strong
+    let word_list = FileWordList::from_reader("apple\nmango".as_bytes(), &FileWordListOptions::default()).unwrap();
+    let mut config = Configuration::xkcd();
+    config.words.num_words = 1;
+    config.words.transformations.clear();
+    let seed: &[_] = &[3];
+
+    let result = try_generate_strong_password_with_rng(&config, &word_list, MAX_SCORE, &mut StdRng::from_seed(seed));
+    assert_eq!(result, Err(GenerateError::TooWeak { min_score: MAX_SCORE, attempts: 100 }));
+}
+
+#[test]
+pub fn test_min_score_above_maximum() {
+    let word_list = FileWordList::from_reader("apple\nmango".as_bytes(), &FileWordListOptions::default()).unwrap();
+    let seed: &[_] = &[3];
+
+    let result = try_generate_strong_password_with_rng(&Configuration::xkcd(), &word_list, MAX_SCORE + 1, &mut StdRng::from_seed(seed));
+    assert_eq!(result, Err(GenerateError::InvalidMinScore(MAX_SCORE + 1)));
+}
@@ -0,0 +1,160 @@
+//! The Electronic Frontier Foundation's Diceware word lists.
+//!
+//! * `EffLarge` is the EFF long word list of 7776 words, designed to be used with five dice.
+//! * `EffShort` is the EFF general short word list of 1296 short words, designed to be used with
+//!   four dice.
+//! * `EffShortUniquePrefix` is the second EFF short word list of 1296 longer words. Every word
+//!   has a unique three letter prefix, so words can be typed with autocomplete.
+//!
+//! `EffLarge` is behind the `eff-large` cargo feature and the two short lists are behind the
+//! `eff-short` cargo feature.
+//!
+//! The word lists are published by the Electronic Frontier Foundation at
+//! https://www.eff.org/dice under the Creative Commons Attribution 3.0 United States license.
+
+use super::{filter_words, WordList, DicewareWordList};
+
+/// Parses a word list in the Diceware format, where each line contains the dice rolls and the
+/// word separated by a tab. The words are returned in the order of the dice rolls
+fn parse_diceware_list(dict: &str) -> Vec<String> {
+    return dict.lines().filter_map(|a| a.split('\t').nth(1)).map(|a| a.trim().to_string()).collect();
+}
+
+/// The EFF long word list of 7776 words. This is loaded at compile time.
+#[cfg(feature = "eff-large")]
+pub struct EffLarge(Vec<String>);
+
+#[cfg(feature = "eff-large")]
+impl EffLarge {
+    /// Creates a new `EffLarge` word list that can be used to generate passwords.
+    ///
+    /// ```
+    /// use xkcd_pass::word_list::{EffLarge, WordList};
+    ///
+    /// let word_list = EffLarge::new();
+    /// assert_eq!(word_list.get_words(0, 255).len(), 7776);
+    /// ```
+    pub fn new() -> EffLarge {
+        return EffLarge(parse_diceware_list(include_str!("dictionaries/eff_large_wordlist.txt")));
+    }
+}
+
+#[cfg(feature = "eff-large")]
+impl Default for EffLarge {
+    fn default() -> EffLarge {
+        return EffLarge::new();
+    }
+}
+
+#[cfg(feature = "eff-large")]
+impl WordList for EffLarge {
+    fn get_words(&self, min_len: u8, max_len: u8) -> Vec<&String> {
+        let EffLarge(ref words) = *self;
+        return filter_words(words, min_len, max_len);
+    }
+}
+
+#[cfg(feature = "eff-large")]
+impl DicewareWordList for EffLarge {
+    fn num_dice(&self) -> usize {
+        return 5;
+    }
+
+    fn diceware_words(&self) -> &[String] {
+        let EffLarge(ref words) = *self;
+        return words;
+    }
+}
+
+/// The EFF general short word list of 1296 words. This is loaded at compile time.
+#[cfg(feature = "eff-short")]
+pub struct EffShort(Vec<String>);
+
+#[cfg(feature = "eff-short")]
+impl EffShort {
+    /// Creates a new `EffShort` word list that can be used to generate passwords.
+    ///
+    /// ```
+    /// use xkcd_pass::word_list::{EffShort, WordList};
+    ///
+    /// let word_list = EffShort::new();
+    /// assert_eq!(word_list.get_words(0, 255).len(), 1296);
+    /// ```
+    pub fn new() -> EffShort {
+        return EffShort(parse_diceware_list(include_str!("dictionaries/eff_short_wordlist_1.txt")));
+    }
+}
+
+#[cfg(feature = "eff-short")]
+impl Default for EffShort {
+    fn default() -> EffShort {
+        return EffShort::new();
+    }
+}
+
+#[cfg(feature = "eff-short")]
+impl WordList for EffShort {
+    fn get_words(&self, min_len: u8, max_len: u8) -> Vec<&String> {
+        let EffShort(ref words) = *self;
+        return filter_words(words, min_len, max_len);
+    }
+}
+
+#[cfg(feature = "eff-short")]
+impl DicewareWordList for EffShort {
+    fn num_dice(&self) -> usize {
+        return 4;
+    }
+
+    fn diceware_words(&self) -> &[String] {
+        let EffShort(ref words) = *self;
+        return words;
+    }
+}
+
+/// The second EFF short word list of 1296 words, where every word has a unique three letter
+/// prefix. This is loaded at compile time.
+#[cfg(feature = "eff-short")]
+pub struct EffShortUniquePrefix(Vec<String>);
+
+#[cfg(feature = "eff-short")]
+impl EffShortUniquePrefix {
+    /// Creates a new `EffShortUniquePrefix` word list that can be used to generate passwords.
+    ///
+    /// ```
+    /// use xkcd_pass::word_list::{EffShortUniquePrefix, WordList};
+    ///
+    /// let word_list = EffShortUniquePrefix::new();
+    /// assert_eq!(word_list.get_words(0, 255).len(), 1296);
+    /// ```
+    pub fn new() -> EffShortUniquePrefix {
+        return EffShortUniquePrefix(parse_diceware_list(include_str!("dictionaries/eff_short_wordlist_2_0.txt")));
+    }
+}
+
+#[cfg(feature = "eff-short")]
+impl Default for EffShortUniquePrefix {
+    fn default() -> EffShortUniquePrefix {
+        return EffShortUniquePrefix::new();
+    }
+}
+
+#[cfg(feature = "eff-short")]
+impl WordList for EffShortUniquePrefix {
+    fn get_words(&self, min_len: u8, max_len: u8) -> Vec<&String> {
+        let EffShortUniquePrefix(ref words) = *self;
+        return filter_words(words, min_len, max_len);
+    }
+}
+
+#[cfg(feature = "eff-short")]
+impl DicewareWordList for EffShortUniquePrefix {
+    fn num_dice(&self) -> usize {
+        return 4;
+    }
+
+    fn diceware_words(&self) -> &[String] {
+        let EffShortUniquePrefix(ref words) = *self;
+        return words;
+    }
+}
@@ -0,0 +1,171 @@
+//! A word list loaded at runtime from a file or any other reader.
+
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+use super::{filter_words, WordList};
+
+/// The options used when loading a `FileWordList`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileWordListOptions {
+    /// Remove everything after a `#` on each line
+    pub strip_comments: bool,
+    /// Remove whitespace from the start and end of each line
+    pub trim_whitespace: bool,
+    /// Only keep the first occurrence of each word
+    pub dedupe: bool,
+    /// Convert every word to lower case
+    pub lowercase: bool,
+    /// Return an error if a word contains anything other than letters
+    pub reject_non_alphabetic: bool,
+}
+
+impl Default for FileWordListOptions {
+    /// Strips comments, trims whitespace and removes duplicate words
+    fn default() -> FileWordListOptions {
+        return FileWordListOptions {
+            strip_comments: true,
+            trim_whitespace: true,
+            dedupe: true,
+            lowercase: false,
+            reject_non_alphabetic: false,
+        };
+    }
+}
+
+/// The reasons a word list could not be loaded
+#[derive(Debug)]
+pub enum FileWordListError {
+    /// The word list could not be read, or is not valid UTF-8
+    Io(io::Error),
+    /// A word contains characters which are not letters
+    NonAlphabetic {
+        /// The line number of the word, starting from 1
+        line: usize,
+        /// The word which contains the characters
+        word: String,
+    },
+    /// The word list does not contain any words
+    Empty,
+}
+
+impl fmt::Display for FileWordListError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match *self {
+            FileWordListError::Io(ref err) => write!(f, "{}", err),
+            FileWordListError::NonAlphabetic { line, ref word } => write!(f, "the word '{}' on line {} contains characters which are not letters", word, line),
+            FileWordListError::Empty => write!(f, "the word list does not contain any words"),
+        };
+    }
+}
+
+impl Error for FileWordListError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        return match *self {
+            FileWordListError::Io(ref err) => Some(err),
+            _ => None,
+        };
+    }
+}
+
+impl From<io::Error> for FileWordListError {
+    fn from(err: io::Error) -> FileWordListError {
+        return FileWordListError::Io(err);
+    }
+}
+
+/// A word list of newline separated words which is loaded at runtime. Blank lines are ignored.
+///
+/// ```
+/// use xkcd_pass::word_list::{FileWordList, FileWordListOptions, WordList};
+///
+/// let contents = "# Vetted words\ncorrect\nhorse\nbattery\nstaple\nhorse\n";
+/// let word_list = FileWordList::from_reader(contents.as_bytes(), &FileWordListOptions::default()).unwrap();
+/// assert_eq!(word_list.len(), 4);
+/// // Only "horse" and "staple" are 5 or 6 letters long
+/// assert_eq!(word_list.get_words(5, 6).len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FileWordList(Vec<String>);
+
+impl FileWordList {
+    /// Loads a word list from a file with the default options.
+    ///
+    /// ```no_run
+    /// use xkcd_pass::word_list::FileWordList;
+    ///
+    /// let word_list = FileWordList::from_path("/usr/share/dict/words").unwrap();
+    /// ```
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<FileWordList, FileWordListError> {
+        return FileWordList::from_path_with_options(path, &FileWordListOptions::default());
+    }
+
+    /// Loads a word list from a file with the given options.
+    pub fn from_path_with_options<P: AsRef<Path>>(path: P, options: &FileWordListOptions) -> Result<FileWordList, FileWordListError> {
+        let file = File::open(path)?;
+        return FileWordList::from_reader(file, options);
+    }
+
+    /// Loads a word list from a reader with the given options.
+    pub fn from_reader<R: Read>(mut reader: R, options: &FileWordListOptions) -> Result<FileWordList, FileWordListError> {
+        let mut contents = String::new();
+        reader.read_to_string(&mut contents)?;
+
+        let mut words = Vec::new();
+        let mut seen = HashSet::new();
+        for (i, line) in contents.lines().enumerate() {
+            let mut word = line;
+            if options.strip_comments {
+                if let Some(comment_start) = word.find('#') {
+                    word = &word[..comment_start];
+                }
+            }
+            if options.trim_whitespace {
+                word = word.trim();
+            }
+            if word.is_empty() {
+                continue;
+            }
+
+            let word = if options.lowercase {
+                word.to_lowercase()
+            } else {
+                word.to_string()
+            };
+            if options.reject_non_alphabetic && !word.chars().all(|a| a.is_alphabetic()) {
+                return Err(FileWordListError::NonAlphabetic { line: i + 1, word });
+            }
+            if options.dedupe && !seen.insert(word.clone()) {
+                continue;
+            }
+            words.push(word);
+        }
+
+        if words.is_empty() {
+            return Err(FileWordListError::Empty);
+        }
+        return Ok(FileWordList(words));
+    }
+
+    /// The number of words in the word list
+    pub fn len(&self) -> usize {
+        let FileWordList(ref words) = *self;
+        return words.len();
+    }
+
+    /// Whether the word list contains no words. A loaded word list is never empty
+    pub fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+}
+
+impl WordList for FileWordList {
+    fn get_words(&self, min_len: u8, max_len: u8) -> Vec<&String> {
+        let FileWordList(ref words) = *self;
+        return filter_words(words, min_len, max_len);
+    }
+}
@@ -0,0 +1,103 @@
+//! A word list which removes unwanted words from another word list.
+//!
+//! Words can be removed with a custom blocklist, the built in list of offensive words and the
+//! built in list of homophones, which are words that sound the same as another word, such as
+//! "knight" and "night". Matching ignores case.
+//!
+//! The entropy calculated with `calculate_entropy` uses the filtered words, so removing words
+//! lowers the entropy accordingly.
+
+use std::collections::HashSet;
+
+use super::WordList;
+
+/// The built in list of offensive words, one word on each line
+const OFFENSIVE_WORDS: &str = include_str!("dictionaries/offensive.txt");
+
+/// The built in list of homophones, one group of words which sound the same on each line
+const HOMOPHONES: &str = include_str!("dictionaries/homophones.txt");
+
+/// The options used when filtering a word list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordFilterOptions {
+    /// Words to remove from the word list
+    pub blocklist: Vec<String>,
+    /// Remove the built in list of offensive words
+    pub remove_offensive: bool,
+    /// Remove words which sound the same as another word when read aloud
+    pub remove_homophones: bool,
+}
+
+impl Default for WordFilterOptions {
+    /// Removes the offensive words and homophones, with an empty blocklist
+    fn default() -> WordFilterOptions {
+        return WordFilterOptions {
+            blocklist: Vec::new(),
+            remove_offensive: true,
+            remove_homophones: true,
+        };
+    }
+}
+
+/// A word list which removes unwanted words from another word list.
+///
+/// ```
+/// use xkcd_pass::{calculate_entropy, Configuration, SimpleEnglish};
+/// use xkcd_pass::word_list::{FilteredWordList, WordFilterOptions, WordList};
+///
+/// let options = WordFilterOptions { blocklist: vec!["Orange".to_string()], ..WordFilterOptions::default() };
+/// let word_list = FilteredWordList::with_options(SimpleEnglish::new(), &options);
+/// assert!(word_list.is_removed("orange"));
+///
+/// // The removed words are not counted in the entropy
+/// let config = Configuration::default();
+/// let filtered = calculate_entropy(&config, &word_list).unwrap();
+/// let unfiltered = calculate_entropy(&config, &SimpleEnglish::new()).unwrap();
+/// assert!(filtered.num_candidate_words < unfiltered.num_candidate_words);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FilteredWordList<W> {
+    word_list: W,
+    removed: HashSet<String>,
+}
+
+impl<W: WordList> FilteredWordList<W> {
+    /// Wraps a word list, removing the offensive words and homophones.
+    pub fn new(word_list: W) -> FilteredWordList<W> {
+        return FilteredWordList::with_options(word_list, &WordFilterOptions::default());
+    }
+
+    /// Wraps a word list, removing the words given by the options.
+    pub fn with_options(word_list: W, options: &WordFilterOptions) -> FilteredWordList<W> {
+        let mut removed: HashSet<String> = options.blocklist.iter().map(|a| a.trim().to_lowercase()).collect();
+        if options.remove_offensive {
+            removed.extend(parse_lines(OFFENSIVE_WORDS).map(|a| a.to_lowercase()));
+        }
+        if options.remove_homophones {
+            removed.extend(parse_lines(HOMOPHONES).flat_map(|a| a.split_whitespace()).map(|a| a.to_lowercase()));
+        }
+
+        return FilteredWordList { word_list, removed };
+    }
+
+    /// Whether the word is removed from the word list
+    pub fn is_removed(&self, word: &str) -> bool {
+        return self.removed.contains(&word.to_lowercase());
+    }
+
+    /// The word list which is being filtered
+    pub fn inner(&self) -> &W {
+        return &self.word_list;
+    }
+}
+
+impl<W: WordList> WordList for FilteredWordList<W> {
+    fn get_words(&self, min_len: u8, max_len: u8) -> Vec<&String> {
+        return self.word_list.get_words(min_len, max_len).into_iter().filter(|a| !self.is_removed(a)).collect();
+    }
+}
+
+/// Gets the lines of a built in list, without the comments and blank lines
+fn parse_lines(list: &str) -> impl Iterator<Item = &str> {
+    return list.lines().map(|a| a.trim()).filter(|a| !a.is_empty() && !a.starts_with('#'));
+}
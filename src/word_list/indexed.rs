@@ -0,0 +1,75 @@
+//! A word list which is indexed by word length, so words within a length range can be borrowed
+//! as a slice without filtering the whole word list.
+
+use super::WordList;
+
+/// A word list sorted by word length. The words of any length range are next to each other, so
+/// `get_words_slice` can return them without allocating. Use it for generating large numbers of
+/// passwords.
+///
+/// ```
+/// use xkcd_pass::word_list::{IndexedWordList, WordList};
+///
+/// let words = vec!["staple".to_string(), "horse".to_string(), "correct".to_string(), "battery".to_string()];
+/// let word_list = IndexedWordList::new(words);
+/// assert_eq!(word_list.get_words_slice(5, 6).unwrap(), &["horse".to_string(), "staple".to_string()][..]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IndexedWordList {
+    /// The words sorted by length
+    words: Vec<String>,
+    /// The index of the first word of each length, with an extra entry for the end of the words
+    offsets: Vec<usize>,
+}
+
+impl IndexedWordList {
+    /// Creates an indexed word list from the given words. Words of the same length stay in the
+    /// same order.
+    pub fn new(mut words: Vec<String>) -> IndexedWordList {
+        words.sort_by_key(|a| a.chars().count());
+
+        let longest_word = words.last().map(|a| a.chars().count()).unwrap_or(0);
+        let offsets = (0..longest_word + 2).map(|len| words.partition_point(|a| a.chars().count() < len)).collect();
+
+        return IndexedWordList { words, offsets };
+    }
+
+    /// Creates an indexed word list from all the words in another word list.
+    ///
+    /// ```no_run
+    /// use xkcd_pass::word_list::{FileWordList, IndexedWordList};
+    ///
+    /// let word_list = IndexedWordList::from_word_list(&FileWordList::from_path("words.txt").unwrap());
+    /// ```
+    pub fn from_word_list<A: WordList>(word_list: &A) -> IndexedWordList {
+        return IndexedWordList::new(word_list.get_words(0, u8::MAX).into_iter().cloned().collect());
+    }
+
+    /// The number of words in the word list
+    pub fn len(&self) -> usize {
+        return self.words.len();
+    }
+
+    /// Whether the word list contains no words
+    pub fn is_empty(&self) -> bool {
+        return self.words.is_empty();
+    }
+}
+
+impl WordList for IndexedWordList {
+    fn get_words(&self, min_len: u8, max_len: u8) -> Vec<&String> {
+        return self.get_words_slice(min_len, max_len).unwrap_or(&[]).iter().collect();
+    }
+
+    fn get_words_slice(&self, min_len: u8, max_len: u8) -> Option<&[String]> {
+        if min_len > max_len {
+            return Some(&[]);
+        }
+
+        // Lengths past the longest word all point to the end of the words
+        let last = self.offsets.len() - 1;
+        let start = self.offsets[(min_len as usize).min(last)];
+        let end = self.offsets[(max_len as usize + 1).min(last)];
+        return Some(&self.words[start..end]);
+    }
+}
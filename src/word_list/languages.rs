@@ -0,0 +1,192 @@
+//! Word lists in languages other than English.
+//!
+//! * `German` is behind the `german` cargo feature
+//! * `French` is behind the `french` cargo feature
+//! * `Spanish` is behind the `spanish` cargo feature
+//! * `Dutch` is behind the `dutch` cargo feature
+//! * `Italian` is behind the `italian` cargo feature
+//!
+//! Each list contains 1626 words, which may contain accented letters. German nouns are
+//! capitalised in the list but, like every other word, are lower cased by the generator before the
+//! word transformations are applied.
+//!
+//! The word lists are the Monero mnemonic seed word lists, Copyright (c) The Monero Project,
+//! published at https://github.com/monero-project/monero under the BSD 3-Clause license. The
+//! license is included in `src/word_list/dictionaries/LICENSE-MONERO`.
+
+use super::{filter_words, WordList};
+
+/// Parses a word list with one word on each line
+fn parse_word_list(dict: &str) -> Vec<String> {
+    return dict.lines().map(|a| a.trim()).filter(|a| !a.is_empty()).map(|a| a.to_string()).collect();
+}
+
+/// The German word list of 1626 words. This is loaded at compile time.
+#[cfg(feature = "german")]
+pub struct German(Vec<String>);
+
+#[cfg(feature = "german")]
+impl German {
+    /// Creates a new `German` word list that can be used to generate passwords.
+    ///
+    /// ```
+    /// use xkcd_pass::word_list::{German, WordList};
+    ///
+    /// let word_list = German::new();
+    /// assert_eq!(word_list.get_words(0, 255).len(), 1626);
+    /// ```
+    pub fn new() -> German {
+        return German(parse_word_list(include_str!("dictionaries/german.txt")));
+    }
+}
+
+#[cfg(feature = "german")]
+impl Default for German {
+    fn default() -> German {
+        return German::new();
+    }
+}
+
+#[cfg(feature = "german")]
+impl WordList for German {
+    fn get_words(&self, min_len: u8, max_len: u8) -> Vec<&String> {
+        let German(ref words) = *self;
+        return filter_words(words, min_len, max_len);
+    }
+}
+
+/// The French word list of 1626 words. This is loaded at compile time.
+#[cfg(feature = "french")]
+pub struct French(Vec<String>);
+
+#[cfg(feature = "french")]
+impl French {
+    /// Creates a new `French` word list that can be used to generate passwords.
+    ///
+    /// ```
+    /// use xkcd_pass::word_list::{French, WordList};
+    ///
+    /// let word_list = French::new();
+    /// assert_eq!(word_list.get_words(0, 255).len(), 1626);
+    /// ```
+    pub fn new() -> French {
+        return French(parse_word_list(include_str!("dictionaries/french.txt")));
+    }
+}
+
+#[cfg(feature = "french")]
+impl Default for French {
+    fn default() -> French {
+        return French::new();
+    }
+}
+
+#[cfg(feature = "french")]
+impl WordList for French {
+    fn get_words(&self, min_len: u8, max_len: u8) -> Vec<&String> {
+        let French(ref words) = *self;
+        return filter_words(words, min_len, max_len);
+    }
+}
+
+/// The Spanish word list of 1626 words. This is loaded at compile time.
+#[cfg(feature = "spanish")]
+pub struct Spanish(Vec<String>);
+
+#[cfg(feature = "spanish")]
+impl Spanish {
+    /// Creates a new `Spanish` word list that can be used to generate passwords.
+    ///
+    /// ```
+    /// use xkcd_pass::word_list::{Spanish, WordList};
+    ///
+    /// let word_list = Spanish::new();
+    /// assert_eq!(word_list.get_words(0, 255).len(), 1626);
+    /// ```
+    pub fn new() -> Spanish {
+        return Spanish(parse_word_list(include_str!("dictionaries/spanish.txt")));
+    }
+}
+
+#[cfg(feature = "spanish")]
+impl Default for Spanish {
+    fn default() -> Spanish {
+        return Spanish::new();
+    }
+}
+
+#[cfg(feature = "spanish")]
+impl WordList for Spanish {
+    fn get_words(&self, min_len: u8, max_len: u8) -> Vec<&String> {
+        let Spanish(ref words) = *self;
+        return filter_words(words, min_len, max_len);
+    }
+}
+
+/// The Dutch word list of 1626 words. This is loaded at compile time.
+#[cfg(feature = "dutch")]
+pub struct Dutch(Vec<String>);
+
+#[cfg(feature = "dutch")]
+impl Dutch {
+    /// Creates a new `Dutch` word list that can be used to generate passwords.
+    ///
+    /// ```
+    /// use xkcd_pass::word_list::{Dutch, WordList};
+    ///
+    /// let word_list = Dutch::new();
+    /// assert_eq!(word_list.get_words(0, 255).len(), 1626);
+    /// ```
+    pub fn new() -> Dutch {
+        return Dutch(parse_word_list(include_str!("dictionaries/dutch.txt")));
+    }
+}
+
+#[cfg(feature = "dutch")]
+impl Default for Dutch {
+    fn default() -> Dutch {
+        return Dutch::new();
+    }
+}
+
+#[cfg(feature = "dutch")]
+impl WordList for Dutch {
+    fn get_words(&self, min_len: u8, max_len: u8) -> Vec<&String> {
+        let Dutch(ref words) = *self;
+        return filter_words(words, min_len, max_len);
+    }
+}
+
+/// The Italian word list of 1626 words. This is loaded at compile time.
+#[cfg(feature = "italian")]
+pub struct Italian(Vec<String>);
+
+#[cfg(feature = "italian")]
+impl Italian {
+    /// Creates a new `Italian` word list that can be used to generate passwords.
+    ///
+    /// ```
+    /// use xkcd_pass::word_list::{Italian, WordList};
+    ///
+    /// let word_list = Italian::new();
+    /// assert_eq!(word_list.get_words(0, 255).len(), 1626);
+    /// ```
+    pub fn new() -> Italian {
+        return Italian(parse_word_list(include_str!("dictionaries/italian.txt")));
+    }
+}
+
+#[cfg(feature = "italian")]
+impl Default for Italian {
+    fn default() -> Italian {
+        return Italian::new();
+    }
+}
+
+#[cfg(feature = "italian")]
+impl WordList for Italian {
+    fn get_words(&self, min_len: u8, max_len: u8) -> Vec<&String> {
+        let Italian(ref words) = *self;
+        return filter_words(words, min_len, max_len);
+    }
+}
@@ -4,13 +4,82 @@
 //! trait.
 
 pub mod simple_english;
+pub mod file;
+pub mod filtered;
+pub mod indexed;
+#[cfg(any(feature = "eff-large", feature = "eff-short"))]
+pub mod eff;
+#[cfg(any(feature = "german", feature = "french", feature = "spanish", feature = "dutch", feature = "italian"))]
+pub mod languages;
 #[cfg(test)]
 mod tests;
 
 pub use self::simple_english::SimpleEnglish;
+pub use self::file::{FileWordList, FileWordListOptions, FileWordListError};
+pub use self::filtered::{FilteredWordList, WordFilterOptions};
+pub use self::indexed::IndexedWordList;
+#[cfg(feature = "eff-large")]
+pub use self::eff::EffLarge;
+#[cfg(feature = "eff-short")]
+pub use self::eff::{EffShort, EffShortUniquePrefix};
+#[cfg(feature = "german")]
+pub use self::languages::German;
+#[cfg(feature = "french")]
+pub use self::languages::French;
+#[cfg(feature = "spanish")]
+pub use self::languages::Spanish;
+#[cfg(feature = "dutch")]
+pub use self::languages::Dutch;
+#[cfg(feature = "italian")]
+pub use self::languages::Italian;
+
+/// Gets the words which are within the minimum and maximum length. The length is the number of
+/// characters rather than bytes, so accented letters are only counted once
+fn filter_words(words: &[String], min_len: u8, max_len: u8) -> Vec<&String> {
+    return words.iter().filter(|&a| {
+        let len = a.chars().count();
+        len >= min_len as usize && len <= max_len as usize
+    }).collect();
+}
 
 /// The trait that all word lists must implement.
 pub trait WordList {
     /// Get all the words in the word list which are within the minimum and maximum length
     fn get_words(&self, min_len: u8, max_len: u8) -> Vec<&String>;
+
+    /// Get all the words in the word list which are within the minimum and maximum length as a
+    /// borrowed slice, without allocating. Word lists which cannot do this return `None`, and
+    /// `get_words` is used instead. See `IndexedWordList`.
+    fn get_words_slice(&self, _min_len: u8, _max_len: u8) -> Option<&[String]> {
+        return None;
+    }
+}
+
+/// A word list in Diceware order, where every word is chosen by rolling a number of six sided
+/// dice. The rolls are read as a base 6 number, so with five dice `11111` is the first word,
+/// `11112` the second and `66666` the last.
+pub trait DicewareWordList: WordList {
+    /// The number of dice rolled to choose each word
+    fn num_dice(&self) -> usize;
+
+    /// Get all the words in the word list in the order of their dice rolls. There must be exactly
+    /// `6.pow(num_dice)` words
+    fn diceware_words(&self) -> &[String];
+
+    /// Get the word for the given dice rolls, e.g. `"41523"`. Returns `None` if the wrong number
+    /// of dice were rolled or a roll is not between 1 and 6.
+    fn get_word_for_rolls(&self, rolls: &str) -> Option<&String> {
+        if rolls.chars().count() != self.num_dice() {
+            return None;
+        }
+
+        let mut index = 0;
+        for roll in rolls.chars() {
+            match roll.to_digit(10) {
+                Some(value @ 1..=6) => index = index * 6 + (value as usize - 1),
+                _ => return None,
+            }
+        }
+        return self.diceware_words().get(index);
+    }
 }
@@ -1,10 +1,18 @@
 //! The simple English word list based on the `simple_en.txt` dictionary.
 
-use super::WordList;
+use std::sync::OnceLock;
+
+use super::{IndexedWordList, WordList};
+
+/// The parsed dictionary, shared by every `SimpleEnglish` word list
+static SIMPLE_ENGLISH: OnceLock<IndexedWordList> = OnceLock::new();
 
 /// The English word list. Currently only loads the simple english dictionary. This is loaded
-/// at compile time. Returns a list of words.
-pub struct SimpleEnglish(Vec<String>);
+/// at compile time and parsed once, the first time a `SimpleEnglish` word list is created. The
+/// words are indexed by length, see `IndexedWordList`, so they are chosen from in length order
+/// rather than file order.
+#[derive(Debug, Clone, Copy)]
+pub struct SimpleEnglish(&'static IndexedWordList);
 
 impl SimpleEnglish {
     /// Creates a new `SimpleEnglish` word list that can be used to generate passwords.
@@ -15,20 +23,30 @@ impl SimpleEnglish {
     /// let word_list = SimpleEnglish::new();
     /// ```
     pub fn new() -> SimpleEnglish {
-        let dict_simple_en = include_str!("dictionaries/simple_en.txt");
-
-        // TODO: More efficient parsing of the dictionaries
-        let words: Vec<&str> = dict_simple_en.split('\n').collect();
-        let words: Vec<String> = words.iter()
-                                      .filter(|&a| a.trim().len() > 0).map(|a| a.to_string()).collect();
+        let words = SIMPLE_ENGLISH.get_or_init(|| {
+            let dict_simple_en = include_str!("dictionaries/simple_en.txt");
+            let words: Vec<String> = dict_simple_en.split('\n').filter(|&a| !a.trim().is_empty()).map(|a| a.to_string()).collect();
+            IndexedWordList::new(words)
+        });
 
         return SimpleEnglish(words);
     }
 }
 
+impl Default for SimpleEnglish {
+    fn default() -> SimpleEnglish {
+        return SimpleEnglish::new();
+    }
+}
+
 impl WordList for SimpleEnglish {
     fn get_words(&self, min_len: u8, max_len: u8) -> Vec<&String> {
-        let &SimpleEnglish(ref words) = self;
-        return words.iter().filter(|&a| a.len() >= min_len as usize && a.len() <= max_len as usize).collect();
+        let SimpleEnglish(words) = *self;
+        return words.get_words(min_len, max_len);
+    }
+
+    fn get_words_slice(&self, min_len: u8, max_len: u8) -> Option<&[String]> {
+        let SimpleEnglish(words) = *self;
+        return words.get_words_slice(min_len, max_len);
     }
 }
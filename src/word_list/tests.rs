@@ -1,13 +1,126 @@
-use super::{WordList, SimpleEnglish};
+use super::{WordList, SimpleEnglish, FileWordList, FileWordListOptions, FileWordListError, FilteredWordList, WordFilterOptions, IndexedWordList};
 
 #[test]
 pub fn test_dictionary_contains_words() {
     let dict = SimpleEnglish::new();
     let words = dict.get_words(0, 5);
 
-    assert!(words.len() != 0);
+    assert!(!words.is_empty());
     // Make sure all the words are less than 5 characters long
     for word in words {
         assert!(word.len() <= 5);
     }
 }
+
+#[test]
+pub fn test_file_word_list_options() {
+    let contents = "  Apple  \napple # duplicate\n\n# comment\nbanana\n";
+
+    let word_list = FileWordList::from_reader(contents.as_bytes(), &FileWordListOptions::default()).unwrap();
+    let words: Vec<&str> = word_list.get_words(0, 255).iter().map(|a| a.as_str()).collect();
+    assert_eq!(words, vec!["Apple", "apple", "banana"]);
+
+    let options = FileWordListOptions { lowercase: true, ..FileWordListOptions::default() };
+    let word_list = FileWordList::from_reader(contents.as_bytes(), &options).unwrap();
+    let words: Vec<&str> = word_list.get_words(0, 255).iter().map(|a| a.as_str()).collect();
+    assert_eq!(words, vec!["apple", "banana"]);
+}
+
+#[test]
+pub fn test_file_word_list_errors() {
+    let options = FileWordListOptions { reject_non_alphabetic: true, ..FileWordListOptions::default() };
+    match FileWordList::from_reader("apple\nbanana2\n".as_bytes(), &options) {
+        Err(FileWordListError::NonAlphabetic { line, word }) => {
+            assert_eq!(line, 2);
+            assert_eq!(word, "banana2");
+        },
+        other => panic!("unexpected result {:?}", other),
+    }
+
+    match FileWordList::from_reader("# only a comment\n\n".as_bytes(), &FileWordListOptions::default()) {
+        Err(FileWordListError::Empty) => {},
+        other => panic!("unexpected result {:?}", other),
+    }
+
+    match FileWordList::from_reader(&[0xff, 0xfe][..], &FileWordListOptions::default()) {
+        Err(FileWordListError::Io(_)) => {},
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[test]
+#[cfg(feature = "eff-large")]
+pub fn test_eff_large_word_list() {
+    use super::EffLarge;
+
+    let words = EffLarge::new();
+    let words = words.get_words(0, 255);
+    assert_eq!(words.len(), 7776);
+    assert_eq!(words[0], "abacus");
+    assert_eq!(words[7775], "zoom");
+}
+
+#[test]
+#[cfg(feature = "eff-short")]
+pub fn test_eff_short_word_lists() {
+    use super::{EffShort, EffShortUniquePrefix};
+
+    let words = EffShort::new();
+    let words = words.get_words(0, 255);
+    assert_eq!(words.len(), 1296);
+    assert_eq!(words[0], "acid");
+
+    let words = EffShortUniquePrefix::new();
+    let words = words.get_words(0, 255);
+    assert_eq!(words.len(), 1296);
+    let mut prefixes: Vec<&str> = words.iter().map(|a| &a[..3]).collect();
+    prefixes.dedup();
+    assert_eq!(prefixes.len(), 1296);
+}
+
+#[test]
+#[cfg(feature = "german")]
+pub fn test_german_word_list_counts_characters() {
+    use super::German;
+
+    let words = German::new();
+    // "Müller" is 6 characters but 7 bytes long
+    assert!(words.get_words(6, 6).iter().any(|a| a.as_str() == "Müller"));
+    assert!(!words.get_words(7, 7).iter().any(|a| a.as_str() == "Müller"));
+}
+
+#[test]
+pub fn test_filtered_word_list() {
+    let contents = "Knight\nnight\nsquirrel\ncrap\nbanana\n";
+    let word_list = FileWordList::from_reader(contents.as_bytes(), &FileWordListOptions::default()).unwrap();
+
+    let filtered = FilteredWordList::new(word_list.clone());
+    let words: Vec<&str> = filtered.get_words(0, 255).iter().map(|a| a.as_str()).collect();
+    assert_eq!(words, vec!["squirrel", "banana"]);
+
+    let options = WordFilterOptions {
+        blocklist: vec!["BANANA".to_string()],
+        remove_offensive: false,
+        remove_homophones: false,
+    };
+    let filtered = FilteredWordList::with_options(word_list, &options);
+    let words: Vec<&str> = filtered.get_words(0, 255).iter().map(|a| a.as_str()).collect();
+    assert_eq!(words, vec!["Knight", "night", "squirrel", "crap"]);
+}
+
+#[test]
+pub fn test_indexed_word_list_matches_filtering() {
+    let contents = "a\nbe\ncat\ndogs\nhorse\nzebra\nélan\ngiraffe\n";
+    let word_list = FileWordList::from_reader(contents.as_bytes(), &FileWordListOptions::default()).unwrap();
+    let indexed = IndexedWordList::from_word_list(&word_list);
+    assert_eq!(indexed.len(), 8);
+
+    for min_len in 0..10 {
+        for max_len in 0..10 {
+            let mut expected = word_list.get_words(min_len, max_len);
+            expected.sort_by_key(|a| a.chars().count());
+            let words: Vec<&String> = indexed.get_words_slice(min_len, max_len).unwrap().iter().collect();
+            assert_eq!(words, expected);
+        }
+    }
+}